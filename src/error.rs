@@ -0,0 +1,62 @@
+use crate::limited::{
+	AvatarUrlError, ExceededLimitError, LimitedUrlError, OwnedExceededLimitError,
+};
+use crate::models::member::{
+	MemberValidationError, ProxyTagComponentError, ProxyTagError, ProxyTagExceededLimitError,
+};
+use crate::models::PrivacyParseError;
+use crate::references::{RefParseError, ShortError};
+use thiserror::Error;
+
+/// Any of the validation errors of this crate, for functions which validate several kinds of values, such as a
+/// `ShortId` and a `LimitedUrl`, and want to use `?` with a single error type.
+///
+/// Every error converts into this, rather than the errors converting into each other, so each error only needs one
+/// conversion. Errors which borrow the invalid input keep borrowing it, so this has the lifetime of the input.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum Error<'a> {
+	#[error("{0}")]
+	ExceededLimit(ExceededLimitError<'a>),
+	#[error(transparent)]
+	OwnedExceededLimit(#[from] OwnedExceededLimitError),
+	#[error("{0}")]
+	LimitedUrl(LimitedUrlError<'a>),
+	#[error("{0}")]
+	AvatarUrl(AvatarUrlError<'a>),
+	#[error(transparent)]
+	Short(#[from] ShortError),
+	#[error(transparent)]
+	RefParse(#[from] RefParseError),
+	#[error(transparent)]
+	PrivacyParse(#[from] PrivacyParseError),
+	#[error(transparent)]
+	ProxyTag(#[from] ProxyTagError),
+	#[error(transparent)]
+	ProxyTagComponent(#[from] ProxyTagComponentError),
+	#[error(transparent)]
+	MemberValidation(#[from] MemberValidationError),
+}
+
+impl<'a> From<ExceededLimitError<'a>> for Error<'a> {
+	fn from(value: ExceededLimitError<'a>) -> Self {
+		Self::ExceededLimit(value)
+	}
+}
+
+impl<'a> From<LimitedUrlError<'a>> for Error<'a> {
+	fn from(value: LimitedUrlError<'a>) -> Self {
+		Self::LimitedUrl(value)
+	}
+}
+
+impl<'a> From<AvatarUrlError<'a>> for Error<'a> {
+	fn from(value: AvatarUrlError<'a>) -> Self {
+		Self::AvatarUrl(value)
+	}
+}
+
+impl From<ProxyTagExceededLimitError> for Error<'_> {
+	fn from(value: ProxyTagExceededLimitError) -> Self {
+		Self::ProxyTag(value.into())
+	}
+}
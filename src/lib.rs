@@ -1,3 +1,11 @@
+pub mod error;
 pub mod limited;
 pub mod models;
+pub mod prelude;
+pub mod proxy;
 pub mod references;
+#[cfg(feature = "serialize_errors")]
+mod serialize_errors;
+pub mod util;
+
+pub use error::Error;
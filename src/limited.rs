@@ -1,12 +1,12 @@
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::fmt::{self, Display, Formatter};
 use std::ops::Deref;
 use thiserror::Error;
 use url::{ParseError, Url};
 
 /// Wrapper around Box<str> which limits it's length to the constant parameter of L, used to enforce PluralKit's length
 /// limits within the library, while not necessarily, this avoids sending any requests which will obviously fail.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(try_from = "&str")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
 pub struct LimitedStr<const L: usize>(Box<str>);
 
 impl<const L: usize> LimitedStr<L> {
@@ -18,6 +18,30 @@ impl<const L: usize> LimitedStr<L> {
 	}
 }
 
+/// Creates a `LimitedStr` from a string literal, checking its length against the limit at compile time rather than
+/// at runtime, so no error handling is needed for hardcoded values.
+///
+/// ```
+/// let name = plurallib::limited_str!(100, "Astralchroma");
+/// ```
+///
+/// A literal which exceeds the limit fails to compile:
+///
+/// ```compile_fail
+/// let name = plurallib::limited_str!(5, "Astralchroma");
+/// ```
+#[macro_export]
+macro_rules! limited_str {
+	($limit:expr, $value:literal) => {{
+		const VALUE: &str = $value;
+		const _: () = assert!(VALUE.len() <= $limit, "string literal exceeds the limit");
+		let value: $crate::limited::LimitedStr<{ $limit }> =
+			// Safety: The length was checked at compile time above.
+			unsafe { $crate::limited::LimitedStr::new_unchecked(VALUE) };
+		value
+	}};
+}
+
 impl<const L: usize> Deref for LimitedStr<L> {
 	type Target = str;
 
@@ -26,9 +50,15 @@ impl<const L: usize> Deref for LimitedStr<L> {
 	}
 }
 
-impl<const L: usize> ToString for LimitedStr<L> {
-	fn to_string(&self) -> String {
-		self.0.to_string()
+impl<const L: usize> AsRef<str> for LimitedStr<L> {
+	fn as_ref(&self) -> &str {
+		&self.0
+	}
+}
+
+impl<const L: usize> Display for LimitedStr<L> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.0)
 	}
 }
 
@@ -43,14 +73,76 @@ impl<'a, const L: usize> TryFrom<&'a str> for LimitedStr<L> {
 	}
 }
 
-#[derive(Error, Debug, Eq, PartialEq)]
+impl<'de, const L: usize> Deserialize<'de> for LimitedStr<L> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let value = String::deserialize(deserializer)?;
+		Self::try_from(value.as_str()).map_err(|error| de::Error::custom(error.into_owned()))
+	}
+}
+
+#[cfg(feature = "schemars")]
+impl<const L: usize> schemars::JsonSchema for LimitedStr<L> {
+	fn is_referenceable() -> bool {
+		false
+	}
+
+	fn schema_name() -> String {
+		format!("LimitedStr_{L}")
+	}
+
+	fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		string_schema(L).into()
+	}
+}
+
+/// Deserializes a `LimitedStr` with surrounding whitespace trimmed, as PluralKit does when storing strings, so that
+/// locally deserialized values compare equal to what the server stores. Use with
+/// `#[serde(deserialize_with = "plurallib::limited::deserialize_trimmed")]`, by default strings are left as they are.
+pub fn deserialize_trimmed<'de, D: Deserializer<'de>, const L: usize>(
+	deserializer: D,
+) -> Result<LimitedStr<L>, D::Error> {
+	let value = String::deserialize(deserializer)?;
+	LimitedStr::try_from(value.trim()).map_err(|error| de::Error::custom(error.into_owned()))
+}
+
+/// Like `deserialize_trimmed`, but for optional fields, such as most of a member's fields. Use with
+/// `#[serde(default, deserialize_with = "plurallib::limited::deserialize_trimmed_option")]`, `null` is left as `None`.
+pub fn deserialize_trimmed_option<'de, D: Deserializer<'de>, const L: usize>(
+	deserializer: D,
+) -> Result<Option<LimitedStr<L>>, D::Error> {
+	match Option::<String>::deserialize(deserializer)? {
+		Some(value) => LimitedStr::try_from(value.trim())
+			.map(Some)
+			.map_err(|error| de::Error::custom(error.into_owned())),
+		None => Ok(None),
+	}
+}
+
+#[derive(Clone, Error, Debug, Eq, PartialEq)]
 #[error("&str \"{0}\" should not exceed length {1}")]
 pub struct ExceededLimitError<'a>(&'a str, usize);
 
+impl ExceededLimitError<'_> {
+	/// Converts into an error which doesn't borrow the string, keeping only its length, for when the string is about
+	/// to be dropped, such as during deserialization.
+	pub fn into_owned(self) -> OwnedExceededLimitError {
+		OwnedExceededLimitError {
+			length: self.0.len(),
+			limit: self.1,
+		}
+	}
+}
+
+#[derive(Clone, Copy, Error, Debug, Eq, PartialEq)]
+#[error("string of length {length} should not exceed length {limit}")]
+pub struct OwnedExceededLimitError {
+	pub length: usize,
+	pub limit: usize,
+}
+
 /// Wrapper around Url which limits it's length to the constant parameter of L, used to enforce PluralKit's length
 /// limits within the library, while not necessarily, this avoids sending any requests which will obviously fail.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(try_from = "&str")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
 pub struct LimitedUrl<const L: usize>(Url);
 
 impl<const L: usize> LimitedUrl<L> {
@@ -60,6 +152,69 @@ impl<const L: usize> LimitedUrl<L> {
 	pub const unsafe fn new_unchecked(url: Url) -> Self {
 		LimitedUrl(url)
 	}
+
+	/// Joins `path` onto `base` using `Url::join`, such as for building an avatar url from a CDN's base url. Note that
+	/// as with `Url::join`, the last segment of `base` is replaced unless it ends with a `/`.
+	pub fn from_base_and_path<'a>(base: &Url, path: &'a str) -> Result<Self, LimitedUrlError<'a>> {
+		let url = base.join(path)?;
+
+		match url.as_str().len() > L {
+			true => Err(OwnedExceededLimitError {
+				length: url.as_str().len(),
+				limit: L,
+			}
+			.into()),
+			false => Ok(Self(url)),
+		}
+	}
+
+	/// Parses a url for use as an avatar or banner, which in addition to the usual checks, rejects urls containing
+	/// userinfo (`user:pass@`) or a fragment, as PluralKit won't accept them.
+	pub fn parse_avatar(value: &str) -> Result<Self, AvatarUrlError<'_>> {
+		let url = Self::try_from(value)?;
+
+		if !url.username().is_empty() || url.password().is_some() {
+			return Err(AvatarUrlError::Userinfo);
+		}
+
+		if url.fragment().is_some() {
+			return Err(AvatarUrlError::Fragment);
+		}
+
+		Ok(url)
+	}
+
+	/// Returns the host of the url, such as the domain of an avatar's CDN for allow listing. This is `None` for urls
+	/// without a host, which can't be used as an avatar or banner anyway.
+	pub fn host_str(&self) -> Option<&str> {
+		self.0.host_str()
+	}
+
+	/// Returns the scheme of the url, lowercase and without the `:`. (example: "https")
+	pub fn scheme(&self) -> &str {
+		self.0.scheme()
+	}
+
+	/// Compares urls ignoring differences that don't change what they point to, a trailing slash on the path and an
+	/// explicit default port, for detecting whether a url has actually changed. `PartialEq` remains an exact comparison.
+	pub fn semantic_eq(&self, other: &Self) -> bool {
+		let (a, b) = (&self.0, &other.0);
+
+		a.scheme() == b.scheme()
+			&& a.username() == b.username()
+			&& a.password() == b.password()
+			&& a.host() == b.host()
+			&& a.port_or_known_default() == b.port_or_known_default()
+			&& a.path().trim_end_matches('/') == b.path().trim_end_matches('/')
+			&& a.query() == b.query()
+			&& a.fragment() == b.fragment()
+	}
+
+	/// Returns the url as a &str, which is guaranteed to not exceed L characters, making it suitable for passing to
+	/// other length limited contexts. This guarantee does not hold for urls created using `new_unchecked`.
+	pub fn as_limited_str(&self) -> &str {
+		self.0.as_str()
+	}
 }
 
 impl<const L: usize> Deref for LimitedUrl<L> {
@@ -70,9 +225,26 @@ impl<const L: usize> Deref for LimitedUrl<L> {
 	}
 }
 
-impl<const L: usize> From<Url> for LimitedUrl<L> {
-	fn from(value: Url) -> Self {
-		Self(value)
+impl<const L: usize> AsRef<str> for LimitedUrl<L> {
+	fn as_ref(&self) -> &str {
+		self.0.as_str()
+	}
+}
+
+impl<const L: usize> Display for LimitedUrl<L> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&self.0, f)
+	}
+}
+
+impl<'a, const L: usize> TryFrom<&'a Url> for LimitedUrl<L> {
+	type Error = LimitedUrlError<'a>;
+
+	fn try_from(value: &'a Url) -> Result<Self, Self::Error> {
+		match value.as_str().len() > L {
+			true => Err(LimitedUrlError::ExceededLimitError(value.as_str(), L)),
+			false => Ok(Self(value.clone())),
+		}
 	}
 }
 
@@ -80,17 +252,91 @@ impl<'a, const L: usize> TryFrom<&'a str> for LimitedUrl<L> {
 	type Error = LimitedUrlError<'a>;
 
 	fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-		match value.len() > L {
+		if value.len() > L {
+			return Err(LimitedUrlError::ExceededLimitError(value, L));
+		}
+
+		// Parsing normalizes the url, which may make it longer than the input, so check again.
+		let url = Url::parse(value)?;
+
+		match url.as_str().len() > L {
 			true => Err(LimitedUrlError::ExceededLimitError(value, L)),
-			false => Ok(Self(Url::parse(value)?)),
+			false => Ok(Self(url)),
 		}
 	}
 }
 
-#[derive(Error, Debug, Eq, PartialEq)]
+/// Parses the string as a url, the length is checked again as parsing may normalize the url to something longer.
+impl<'a, const L: usize> TryFrom<&'a LimitedStr<L>> for LimitedUrl<L> {
+	type Error = LimitedUrlError<'a>;
+
+	fn try_from(value: &'a LimitedStr<L>) -> Result<Self, Self::Error> {
+		Self::try_from(&**value)
+	}
+}
+
+impl<'de, const L: usize> Deserialize<'de> for LimitedUrl<L> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let value = String::deserialize(deserializer)?;
+		Self::try_from(value.as_str()).map_err(de::Error::custom)
+	}
+}
+
+#[cfg(feature = "schemars")]
+impl<const L: usize> schemars::JsonSchema for LimitedUrl<L> {
+	fn is_referenceable() -> bool {
+		false
+	}
+
+	fn schema_name() -> String {
+		format!("LimitedUrl_{L}")
+	}
+
+	fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		let mut schema = string_schema(L);
+		schema.format = Some("uri".into());
+		schema.into()
+	}
+}
+
+/// Schema of a string with a maximum length of `limit`, shared by `LimitedStr` and `LimitedUrl`.
+#[cfg(feature = "schemars")]
+fn string_schema(limit: usize) -> schemars::schema::SchemaObject {
+	use schemars::schema::{InstanceType, SchemaObject, StringValidation};
+
+	SchemaObject {
+		instance_type: Some(InstanceType::String.into()),
+		string: Some(Box::new(StringValidation {
+			max_length: u32::try_from(limit).ok(),
+			..Default::default()
+		})),
+		..Default::default()
+	}
+}
+
+#[derive(Clone, Error, Debug, Eq, PartialEq)]
 pub enum LimitedUrlError<'a> {
 	#[error("Url \"{0}\" should not exceed length {1}")]
 	ExceededLimitError(&'a str, usize),
+	/// For urls which are built rather than parsed from a string, so there is no string to borrow.
+	#[error(transparent)]
+	OwnedExceededLimitError(#[from] OwnedExceededLimitError),
 	#[error(transparent)]
 	ParseError(#[from] ParseError),
 }
+
+#[derive(Clone, Error, Debug, Eq, PartialEq)]
+pub enum AvatarUrlError<'a> {
+	#[error("{0}")]
+	LimitedUrlError(LimitedUrlError<'a>),
+	#[error("Avatar urls should not contain userinfo")]
+	Userinfo,
+	#[error("Avatar urls should not contain a fragment")]
+	Fragment,
+}
+
+impl<'a> From<LimitedUrlError<'a>> for AvatarUrlError<'a> {
+	fn from(value: LimitedUrlError<'a>) -> Self {
+		Self::LimitedUrlError(value)
+	}
+}
@@ -1,14 +1,22 @@
 use crate::limited::{LimitedStr, LimitedUrl};
+#[cfg(feature = "serde_json")]
+use crate::models::FromValueError;
 use crate::models::{Patchable, Privacy};
-use crate::references::ShortId;
+use crate::references::{AsReference, GenericRef, RefParseError, ShortId};
+use crate::util::to_unix_millis;
+use bitflags::bitflags;
 use rgb::RGB8;
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use thiserror::Error;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
+/// Members are compared and hashed by their uuid alone, as it identifies the member, so two versions of the same member
+/// fetched at different times are equal even if their fields differ. Use `Member::content_eq` to compare every field.
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Member {
 	pub id: ShortId,
 	pub uuid: Uuid,
@@ -17,8 +25,16 @@ pub struct Member {
 	pub name: LimitedStr<100>,
 	pub display_name: Option<LimitedStr<100>>,
 	#[serde(with = "crate::models::color")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::color::schema")
+	)]
 	pub color: Option<RGB8>,
-	#[serde(with = "time::serde::iso8601::option")]
+	#[serde(deserialize_with = "crate::models::datetime::deserialize")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::birthday::schema")
+	)]
 	pub birthday: Option<OffsetDateTime>,
 	pub pronouns: Option<LimitedStr<100>>,
 	#[serde(rename = "avatar_url")]
@@ -28,19 +44,320 @@ pub struct Member {
 	pub banner: Option<LimitedUrl<256>>,
 	pub description: Option<LimitedStr<1000>>,
 	#[serde(with = "time::serde::iso8601::option")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::datetime::schema")
+	)]
 	pub created: Option<OffsetDateTime>,
 	pub proxy_tags: Vec<ProxyTag>,
+	// These may be omitted when privacy settings restrict what can be seen of the member.
+	#[serde(default)]
 	#[serde(rename = "keep_proxy")]
 	pub keep_proxy_tags: bool,
+	#[serde(default)]
 	pub text_to_speech: bool,
 	pub autoproxy_enabled: Option<bool>,
+	#[serde(default)]
+	#[serde(deserialize_with = "crate::models::count::deserialize")]
 	pub message_count: Option<u32>,
 	#[serde(with = "time::serde::iso8601::option")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::datetime::schema")
+	)]
 	pub last_message_timestamp: Option<OffsetDateTime>,
 	pub privacy: Option<MemberPrivacy>,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize)]
+impl Member {
+	/// Whether this member was fetched by the system which owns it. PluralKit only includes the privacy settings when
+	/// the member is requested by its own system, so this checks for their presence. This is a heuristic, a member
+	/// constructed or deserialized from elsewhere without privacy settings will be reported as not owned.
+	pub fn is_own(&self) -> bool {
+		self.privacy.is_some()
+	}
+
+	/// Compares every field of the members, unlike `PartialEq` which only compares their uuids.
+	pub fn content_eq(&self, other: &Member) -> bool {
+		// Destructured without `..`, so that adding a field to `Member` fails to compile until it's compared here.
+		let Member {
+			id,
+			uuid,
+			system_id,
+			name,
+			display_name,
+			color,
+			birthday,
+			pronouns,
+			avatar,
+			webhook_avatar,
+			banner,
+			description,
+			created,
+			proxy_tags,
+			keep_proxy_tags,
+			text_to_speech,
+			autoproxy_enabled,
+			message_count,
+			last_message_timestamp,
+			privacy,
+		} = self;
+
+		*id == other.id
+			&& *uuid == other.uuid
+			&& *system_id == other.system_id
+			&& *name == other.name
+			&& *display_name == other.display_name
+			&& *color == other.color
+			&& *birthday == other.birthday
+			&& *pronouns == other.pronouns
+			&& *avatar == other.avatar
+			&& *webhook_avatar == other.webhook_avatar
+			&& *banner == other.banner
+			&& *description == other.description
+			&& *created == other.created
+			&& *proxy_tags == other.proxy_tags
+			&& *keep_proxy_tags == other.keep_proxy_tags
+			&& *text_to_speech == other.text_to_speech
+			&& *autoproxy_enabled == other.autoproxy_enabled
+			&& *message_count == other.message_count
+			&& *last_message_timestamp == other.last_message_timestamp
+			&& *privacy == other.privacy
+	}
+
+	/// Returns when the member was created, in milliseconds since the Unix epoch, see `util::to_unix_millis`.
+	pub fn created_unix_millis(&self) -> Option<i64> {
+		self.created.as_ref().map(to_unix_millis)
+	}
+
+	/// Whether autoproxy is enabled for this member, treating an unknown value as disabled.
+	pub fn is_autoproxy_enabled(&self) -> bool {
+		self.autoproxy_enabled.unwrap_or(false)
+	}
+
+	/// Returns the member's proxy tags joined by ", ", for listing them in a summary, or an empty string if there are
+	/// none. (example: "\[text\], text-")
+	pub fn proxy_tags_display(&self) -> String {
+		self.proxy_tags
+			.iter()
+			.map(ToString::to_string)
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+
+	/// Returns which aspects of the member are public, or `None` if the privacy settings aren't known, see
+	/// `Member::is_own`.
+	pub fn visibility(&self) -> Option<MemberVisibility> {
+		self.privacy.as_ref().map(MemberVisibility::from)
+	}
+
+	/// Returns a body for creating a copy of this member, such as in another system when migrating. Ids, counts and
+	/// timestamps are left behind, while the editable content, proxy tags and privacy settings are kept, unless any
+	/// privacy level is `Unknown`, in which case PluralKit's defaults apply. Equivalent to `NewMember::from`.
+	pub fn to_new_member(&self) -> NewMember {
+		NewMember::from(self)
+	}
+
+	/// Returns a wrapper which debug prints the member with the description, pronouns and birthday replaced by
+	/// `<redacted>`, for logging members without leaking personal information.
+	pub fn debug_redacted(&self) -> RedactedMember<'_> {
+		RedactedMember(self)
+	}
+
+	/// Returns the description shortened to at most `max_chars` characters for use as a preview, cutting at the last
+	/// word boundary where possible and ending with an ellipsis when shortened.
+	pub fn description_preview(&self, max_chars: usize) -> Option<String> {
+		let description = self.description.as_deref()?;
+
+		if description.chars().count() <= max_chars {
+			return Some(description.to_string());
+		}
+
+		// Leave space for the ellipsis.
+		let Some(limit) = max_chars.checked_sub(1) else {
+			return Some(String::new());
+		};
+
+		let end = description
+			.char_indices()
+			.nth(limit)
+			.map_or(description.len(), |(index, _)| index);
+		let truncated = &description[..end];
+
+		// Only back off to the previous word boundary if the cut falls in the middle of a word.
+		let truncated = match description[end..].starts_with(char::is_whitespace) {
+			true => truncated,
+			false => match truncated.rfind(char::is_whitespace) {
+				Some(index) if index > 0 => &truncated[..index],
+				_ => truncated,
+			},
+		};
+
+		Some(format!("{}…", truncated.trim_end()))
+	}
+}
+
+/// References the member by its uuid, as unlike its short id, it can't change.
+impl AsReference for Member {
+	fn as_reference(&self) -> Result<GenericRef, RefParseError> {
+		Ok(GenericRef::Uuid(self.uuid))
+	}
+}
+
+impl PartialEq for Member {
+	fn eq(&self, other: &Self) -> bool {
+		self.uuid == other.uuid
+	}
+}
+
+impl Eq for Member {}
+
+impl Hash for Member {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.uuid.hash(state);
+	}
+}
+
+/// Sorts members by their uuid. Unlike short ids and names, uuids never change, so this ordering stays the same across
+/// fetches, which is useful for producing reproducible diffs of a system.
+pub fn sort_members_by_uuid(members: &mut [Member]) {
+	members.sort_by_key(|member| member.uuid);
+}
+
+/// Returns the members of `all` which belong to a group. Groups don't contain their members, membership is a separate
+/// relation fetched from `/groups/{ref}/members`, so this takes the ids from there and resolves them against an
+/// already fetched list of the system's members. Members are returned in the order of `all`, and ids without a
+/// matching member are ignored.
+pub fn members_in_group<'a>(group_members: &[ShortId], all: &'a [Member]) -> Vec<&'a Member> {
+	all.iter()
+		.filter(|member| group_members.contains(&member.id))
+		.collect()
+}
+
+/// Returns the members whose short id starts with `prefix`, ignoring case, such as for autocompleting a member argument.
+pub fn members_with_id_prefix<'a: 'p, 'p>(
+	members: &'a [Member],
+	prefix: &'p str,
+) -> impl Iterator<Item = &'a Member> + 'p {
+	members
+		.iter()
+		.filter(move |member| starts_with_ignore_case(&member.id, prefix))
+}
+
+/// Returns the members whose name starts with `prefix`, ignoring case, such as for autocompleting a member argument.
+/// Only `name` is checked, not `display_name`.
+pub fn members_with_name_prefix<'a: 'p, 'p>(
+	members: &'a [Member],
+	prefix: &'p str,
+) -> impl Iterator<Item = &'a Member> + 'p {
+	members
+		.iter()
+		.filter(move |member| starts_with_ignore_case(&member.name, prefix))
+}
+
+fn starts_with_ignore_case(value: &str, prefix: &str) -> bool {
+	let mut value = value.chars().flat_map(char::to_lowercase);
+
+	prefix
+		.chars()
+		.flat_map(char::to_lowercase)
+		.all(|char| value.next() == Some(char))
+}
+
+/// See `Member::debug_redacted`.
+pub struct RedactedMember<'a>(&'a Member);
+
+impl Debug for RedactedMember<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		struct Redacted;
+
+		impl Debug for Redacted {
+			fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+				f.write_str("<redacted>")
+			}
+		}
+
+		let member = self.0;
+
+		f.debug_struct("Member")
+			.field("id", &member.id)
+			.field("uuid", &member.uuid)
+			.field("system_id", &member.system_id)
+			.field("name", &member.name)
+			.field("display_name", &member.display_name)
+			.field("color", &member.color)
+			.field("birthday", &member.birthday.map(|_| Redacted))
+			.field("pronouns", &member.pronouns.as_ref().map(|_| Redacted))
+			.field("avatar", &member.avatar)
+			.field("webhook_avatar", &member.webhook_avatar)
+			.field("banner", &member.banner)
+			.field(
+				"description",
+				&member.description.as_ref().map(|_| Redacted),
+			)
+			.field("created", &member.created)
+			.field("proxy_tags", &member.proxy_tags)
+			.field("keep_proxy_tags", &member.keep_proxy_tags)
+			.field("text_to_speech", &member.text_to_speech)
+			.field("autoproxy_enabled", &member.autoproxy_enabled)
+			.field("message_count", &member.message_count)
+			.field("last_message_timestamp", &member.last_message_timestamp)
+			.field("privacy", &member.privacy)
+			.finish()
+	}
+}
+
+#[cfg(feature = "serde_json")]
+impl TryFrom<serde_json::Value> for Member {
+	type Error = FromValueError;
+
+	fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+		Ok(serde_json::from_value(value)?)
+	}
+}
+
+#[cfg(feature = "serde_json")]
+impl Member {
+	/// Parses a member from json, on failure the error includes the path to the offending field, which makes
+	/// debugging unexpected API responses considerably easier than the bare serde error.
+	pub fn from_json(json: &str) -> Result<Member, MemberParseError> {
+		let deserializer = &mut serde_json::Deserializer::from_str(json);
+
+		serde_path_to_error::deserialize(deserializer).map_err(|error| MemberParseError {
+			path: error.path().to_string(),
+			source: error.into_inner(),
+		})
+	}
+}
+
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Error)]
+#[error("failed to parse member field \"{path}\": {source}")]
+pub struct MemberParseError {
+	pub path: String,
+	pub source: serde_json::Error,
+}
+
+/// Either a single `Member` or a list of them, for handling responses which may contain either shape, such as
+/// `GET /members/{ref}` and `GET /systems/{ref}/members`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum MemberResponse {
+	Single(Box<Member>),
+	List(Vec<Member>),
+}
+
+impl MemberResponse {
+	pub fn into_vec(self) -> Vec<Member> {
+		match self {
+			MemberResponse::Single(member) => vec![*member],
+			MemberResponse::List(members) => members,
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MemberPrivacy {
 	pub visibility: Privacy,
 	pub name: Privacy,
@@ -51,9 +368,80 @@ pub struct MemberPrivacy {
 	pub metadata: Privacy,
 }
 
+impl MemberPrivacy {
+	pub const fn all(privacy: Privacy) -> MemberPrivacy {
+		MemberPrivacy {
+			visibility: privacy,
+			name: privacy,
+			description: privacy,
+			birthday: privacy,
+			pronouns: privacy,
+			avatar: privacy,
+			metadata: privacy,
+		}
+	}
+
+	/// Whether any aspect has an `Unknown` privacy level, which can't be sent back to PluralKit.
+	pub fn has_unknown(&self) -> bool {
+		[
+			self.visibility,
+			self.name,
+			self.description,
+			self.birthday,
+			self.pronouns,
+			self.avatar,
+			self.metadata,
+		]
+		.contains(&Privacy::Unknown)
+	}
+}
+
+bitflags! {
+	/// The aspects of a member which are public, as a compact alternative to `MemberPrivacy` for checking many members.
+	/// Aspects with an `Unknown` privacy level are treated as private.
+	#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+	pub struct MemberVisibility: u8 {
+		const VISIBILITY = 1 << 0;
+		const NAME = 1 << 1;
+		const DESCRIPTION = 1 << 2;
+		const BIRTHDAY = 1 << 3;
+		const PRONOUNS = 1 << 4;
+		const AVATAR = 1 << 5;
+		const METADATA = 1 << 6;
+	}
+}
+
+impl From<&MemberPrivacy> for MemberVisibility {
+	fn from(privacy: &MemberPrivacy) -> Self {
+		[
+			(privacy.visibility, MemberVisibility::VISIBILITY),
+			(privacy.name, MemberVisibility::NAME),
+			(privacy.description, MemberVisibility::DESCRIPTION),
+			(privacy.birthday, MemberVisibility::BIRTHDAY),
+			(privacy.pronouns, MemberVisibility::PRONOUNS),
+			(privacy.avatar, MemberVisibility::AVATAR),
+			(privacy.metadata, MemberVisibility::METADATA),
+		]
+		.into_iter()
+		.filter(|(privacy, _)| *privacy == Privacy::Public)
+		.fold(MemberVisibility::empty(), |visibility, (_, flag)| {
+			visibility | flag
+		})
+	}
+}
+
+/// PluralKit defaults every privacy setting of a new member to public.
+impl Default for MemberPrivacy {
+	fn default() -> Self {
+		Self::all(Privacy::Public)
+	}
+}
+
 const PROXY_TAG_SIZE_LIMIT: usize = 100;
+const PROXY_TAG_COUNT_LIMIT: usize = 100;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ProxyTag {
 	pub prefix: Option<Box<str>>,
 	pub suffix: Option<Box<str>>,
@@ -67,34 +455,273 @@ impl ProxyTag {
 	where
 		S: Into<Box<str>> + Clone + Debug + Serialize,
 	{
-		let mut length = 0;
-
-		let mut validate = |parameter: Option<S>| {
-			Ok(match parameter {
-				Some(parameter) => {
-					let string = parameter.into();
-					length += string.len();
-					if length > PROXY_TAG_SIZE_LIMIT {
-						return Err(ProxyTagExceededLimitError);
-					}
-					Some(string)
+		let prefix = prefix.map(Into::into);
+		let suffix = suffix.map(Into::into);
+
+		let count =
+			|part: &Option<Box<str>>| part.as_deref().map_or(0, |part| part.chars().count());
+
+		// On overflow the length is certainly over the limit, so saturate rather than wrap.
+		let length = count(&prefix).saturating_add(count(&suffix));
+
+		if length > PROXY_TAG_SIZE_LIMIT {
+			return Err(ProxyTagExceededLimitError { length });
+		}
+
+		Ok(ProxyTag { prefix, suffix })
+	}
+
+	/// Returns a builder for constructing a proxy tag from a separately provided prefix and suffix, such as from two
+	/// fields of user input, which reports which of the two pushed the tag over the limit.
+	pub fn builder() -> ProxyTagBuilder {
+		ProxyTagBuilder::default()
+	}
+
+	/// Creates a proxy tag from already boxed strings without reallocating them. Unlike `new`, this also rejects tags
+	/// with neither a prefix nor a suffix.
+	pub fn from_boxed(
+		prefix: Option<Box<str>>,
+		suffix: Option<Box<str>>,
+	) -> Result<ProxyTag, ProxyTagError> {
+		let tag = Self::new(prefix, suffix)?;
+
+		match tag.is_empty() {
+			true => Err(ProxyTagError::Empty),
+			false => Ok(tag),
+		}
+	}
+
+	/// Whether the tag has neither a prefix nor a suffix, PluralKit rejects such tags.
+	pub fn is_empty(&self) -> bool {
+		self.prefix.as_deref().unwrap_or_default().is_empty()
+			&& self.suffix.as_deref().unwrap_or_default().is_empty()
+	}
+
+	/// Like `Display`, but with Discord markdown characters in the prefix and suffix escaped with a `\`, so the tag
+	/// renders literally when included in a message. (example: "\*text\*")
+	pub fn display_escaped(&self) -> String {
+		fn escape(escaped: &mut String, part: Option<&str>) {
+			for char in part.unwrap_or_default().chars() {
+				if matches!(char, '\\' | '`' | '*' | '_' | '~' | '|') {
+					escaped.push('\\');
 				}
-				None => None,
-			})
-		};
+
+				escaped.push(char);
+			}
+		}
+
+		let mut escaped = String::new();
+		escape(&mut escaped, self.prefix.as_deref());
+		escaped.push_str("text");
+		escape(&mut escaped, self.suffix.as_deref());
+
+		escaped
+	}
+}
+
+/// See `ProxyTag::builder`.
+#[derive(Clone, Debug, Default)]
+pub struct ProxyTagBuilder {
+	prefix: Option<Box<str>>,
+	suffix: Option<Box<str>>,
+}
+
+impl ProxyTagBuilder {
+	pub fn prefix<S: Into<Box<str>>>(mut self, prefix: S) -> Self {
+		self.prefix = Some(prefix.into());
+		self
+	}
+
+	pub fn suffix<S: Into<Box<str>>>(mut self, suffix: S) -> Self {
+		self.suffix = Some(suffix.into());
+		self
+	}
+
+	/// The prefix is counted first, so if the prefix fits within the limit on its own, the suffix is blamed for the
+	/// overflow.
+	pub fn build(self) -> Result<ProxyTag, ProxyTagComponentError> {
+		let count =
+			|part: &Option<Box<str>>| part.as_deref().map_or(0, |part| part.chars().count());
+
+		let length = count(&self.prefix);
+
+		if length > PROXY_TAG_SIZE_LIMIT {
+			return Err(ProxyTagComponentError {
+				component: ProxyTagComponent::Prefix,
+				length,
+			});
+		}
+
+		let length = length.saturating_add(count(&self.suffix));
+
+		if length > PROXY_TAG_SIZE_LIMIT {
+			return Err(ProxyTagComponentError {
+				component: ProxyTagComponent::Suffix,
+				length,
+			});
+		}
 
 		Ok(ProxyTag {
-			prefix: validate(prefix)?,
-			suffix: validate(suffix)?,
+			prefix: self.prefix,
+			suffix: self.suffix,
 		})
 	}
 }
 
+/// Displays the tag the way PluralKit does, with "text" between the prefix and suffix. (example: "\[text\]")
+impl Display for ProxyTag {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(self.prefix.as_deref().unwrap_or_default())?;
+		f.write_str("text")?;
+		f.write_str(self.suffix.as_deref().unwrap_or_default())
+	}
+}
+
 #[derive(Clone, Debug, Error, Eq, PartialEq)]
-#[error("proxy tags must not exceed 100 total characters")]
-pub struct ProxyTagExceededLimitError;
+#[error("proxy tags were {length} characters but must not exceed 100")]
+pub struct ProxyTagExceededLimitError {
+	pub length: usize,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProxyTagComponent {
+	Prefix,
+	Suffix,
+}
+
+impl Display for ProxyTagComponent {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			ProxyTagComponent::Prefix => "prefix",
+			ProxyTagComponent::Suffix => "suffix",
+		})
+	}
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+#[error(
+	"the {component} brought the proxy tags to {length} characters but they must not exceed 100"
+)]
+pub struct ProxyTagComponentError {
+	pub component: ProxyTagComponent,
+	pub length: usize,
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum ProxyTagError {
+	#[error(transparent)]
+	ExceededLimit(#[from] ProxyTagExceededLimitError),
+	#[error("proxy tags must have a prefix or a suffix")]
+	Empty,
+}
+
+/// Body for creating a new member, only the name is required, any field left as `None` uses PluralKit's default.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NewMember {
+	pub name: LimitedStr<100>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub display_name: Option<LimitedStr<100>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(serialize_with = "crate::models::color::serialize")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::color::schema")
+	)]
+	pub color: Option<RGB8>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(serialize_with = "crate::models::birthday::serialize")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::birthday::schema")
+	)]
+	pub birthday: Option<OffsetDateTime>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub pronouns: Option<LimitedStr<100>>,
+	#[serde(rename = "avatar_url")]
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub avatar: Option<LimitedUrl<256>>,
+	#[serde(rename = "webhook_avatar_url")]
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub webhook_avatar: Option<LimitedUrl<256>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub banner: Option<LimitedUrl<256>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<LimitedStr<1000>>,
+	#[serde(skip_serializing_if = "crate::models::proxy_tags::is_empty")]
+	#[serde(serialize_with = "crate::models::proxy_tags::serialize")]
+	pub proxy_tags: Vec<ProxyTag>,
+	#[serde(rename = "keep_proxy")]
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub keep_proxy_tags: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text_to_speech: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub autoproxy_enabled: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub privacy: Option<MemberPrivacy>,
+}
+
+impl NewMember {
+	pub fn new(name: LimitedStr<100>) -> NewMember {
+		NewMember {
+			name,
+			display_name: None,
+			color: None,
+			birthday: None,
+			pronouns: None,
+			avatar: None,
+			webhook_avatar: None,
+			banner: None,
+			description: None,
+			proxy_tags: Vec::new(),
+			keep_proxy_tags: None,
+			text_to_speech: None,
+			autoproxy_enabled: None,
+			privacy: None,
+		}
+	}
+
+	/// Checks for values which fit within the field limits but would still be rejected by PluralKit when creating a
+	/// member, unlike a patch, the name is always sent so must not be empty.
+	pub fn validate(&self) -> Result<(), MemberValidationError> {
+		validate_name(&self.name)?;
+		validate_proxy_tags(&self.proxy_tags)?;
+
+		match &self.birthday {
+			Some(birthday) => validate_birthday(birthday),
+			None => Ok(()),
+		}
+	}
+}
+
+/// Copies the editable fields of an existing member, for example to duplicate a member, ids are not copied. Privacy
+/// settings are only copied if none of them are `Unknown`, otherwise PluralKit's defaults apply.
+impl From<&Member> for NewMember {
+	fn from(member: &Member) -> Self {
+		NewMember {
+			name: member.name.clone(),
+			display_name: member.display_name.clone(),
+			color: member.color,
+			birthday: member.birthday,
+			pronouns: member.pronouns.clone(),
+			avatar: member.avatar.clone(),
+			webhook_avatar: member.webhook_avatar.clone(),
+			banner: member.banner.clone(),
+			description: member.description.clone(),
+			proxy_tags: member.proxy_tags.clone(),
+			keep_proxy_tags: Some(member.keep_proxy_tags),
+			text_to_speech: Some(member.text_to_speech),
+			autoproxy_enabled: member.autoproxy_enabled,
+			// Unknown privacy levels can't be sent, so leave the privacy to PluralKit's defaults instead.
+			privacy: member.privacy.filter(|privacy| !privacy.has_unknown()),
+		}
+	}
+}
 
-#[derive(Clone, Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(default))]
 pub struct MemberPatch {
 	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
 	pub name: Patchable<LimitedStr<100>>,
@@ -102,9 +729,17 @@ pub struct MemberPatch {
 	pub display_name: Patchable<Option<LimitedStr<100>>>,
 	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
 	#[serde(with = "crate::models::patchable_color")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::color::schema")
+	)]
 	pub color: Patchable<Option<RGB8>>,
 	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
-	#[serde(with = "crate::models::patchable_datetime")]
+	#[serde(with = "crate::models::patchable_birthday")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::birthday::schema")
+	)]
 	pub birthday: Patchable<Option<OffsetDateTime>>,
 	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
 	pub pronouns: Patchable<Option<LimitedStr<100>>>,
@@ -118,7 +753,10 @@ pub struct MemberPatch {
 	pub banner: Patchable<Option<LimitedUrl<256>>>,
 	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
 	pub description: Patchable<Option<LimitedStr<1000>>>,
-	pub proxy_tags: Vec<ProxyTag>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	#[serde(with = "crate::models::patchable_proxy_tags")]
+	#[cfg_attr(feature = "schemars", schemars(with = "Vec<ProxyTag>"))]
+	pub proxy_tags: Patchable<Vec<ProxyTag>>,
 	#[serde(rename = "keep_proxy")]
 	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
 	pub keep_proxy_tags: Patchable<bool>,
@@ -130,7 +768,149 @@ pub struct MemberPatch {
 	pub privacy: Patchable<MemberPrivacyPatch>,
 }
 
-#[derive(Clone, Debug, Default, Serialize)]
+impl MemberPatch {
+	/// Creates a patch which only modifies the member's privacy, leaving every other field untouched.
+	pub fn privacy_only(privacy: MemberPrivacyPatch) -> MemberPatch {
+		MemberPatch {
+			privacy: Patchable::Patched(privacy),
+			..Default::default()
+		}
+	}
+
+	/// Combines two patches, fields modified in `other` take priority, while fields it leaves unmodified keep their
+	/// value from `self`. Privacy is merged field by field in the same way.
+	pub fn merge(self, other: MemberPatch) -> MemberPatch {
+		let privacy = match (self.privacy, other.privacy) {
+			(Patchable::Patched(privacy), Patchable::Patched(other)) => {
+				Patchable::Patched(privacy.merge(other))
+			}
+			(privacy, other) => other.or(privacy),
+		};
+
+		MemberPatch {
+			name: other.name.or(self.name),
+			display_name: other.display_name.or(self.display_name),
+			color: other.color.or(self.color),
+			birthday: other.birthday.or(self.birthday),
+			pronouns: other.pronouns.or(self.pronouns),
+			avatar: other.avatar.or(self.avatar),
+			webhook_avatar: other.webhook_avatar.or(self.webhook_avatar),
+			banner: other.banner.or(self.banner),
+			description: other.description.or(self.description),
+			proxy_tags: other.proxy_tags.or(self.proxy_tags),
+			keep_proxy_tags: other.keep_proxy_tags.or(self.keep_proxy_tags),
+			text_to_speech: other.text_to_speech.or(self.text_to_speech),
+			autoproxy_enabled: other.autoproxy_enabled.or(self.autoproxy_enabled),
+			privacy,
+		}
+	}
+
+	/// Whether applying the patch to `member` would leave it unchanged, for skipping requests which wouldn't do anything.
+	/// Patching the privacy of a member whose privacy settings aren't known is never considered a no-op, and empty
+	/// proxy tags are ignored, as they're dropped when the patch is sent.
+	pub fn is_noop_against(&self, member: &Member) -> bool {
+		let proxy_tags = match &self.proxy_tags {
+			Patchable::Patched(proxy_tags) => proxy_tags
+				.iter()
+				.filter(|tag| !tag.is_empty())
+				.eq(&member.proxy_tags),
+			Patchable::Unmodified => true,
+		};
+
+		let privacy = match (&self.privacy, &member.privacy) {
+			(Patchable::Unmodified, _) => true,
+			(Patchable::Patched(patch), Some(privacy)) => patch.is_noop_against(privacy),
+			(Patchable::Patched(_), None) => false,
+		};
+
+		self.name.is_noop_against(&member.name)
+			&& self.display_name.is_noop_against(&member.display_name)
+			&& self.color.is_noop_against(&member.color)
+			&& self.birthday.is_noop_against(&member.birthday)
+			&& self.pronouns.is_noop_against(&member.pronouns)
+			&& self.avatar.is_noop_against(&member.avatar)
+			&& self.webhook_avatar.is_noop_against(&member.webhook_avatar)
+			&& self.banner.is_noop_against(&member.banner)
+			&& self.description.is_noop_against(&member.description)
+			&& proxy_tags
+			&& self
+				.keep_proxy_tags
+				.is_noop_against(&member.keep_proxy_tags)
+			&& self.text_to_speech.is_noop_against(&member.text_to_speech)
+			&& self
+				.autoproxy_enabled
+				.is_noop_against(&member.autoproxy_enabled)
+			&& privacy
+	}
+
+	/// Checks for values which fit within the field limits but would still be rejected by PluralKit. Note that
+	/// clearing `display_name` is allowed, only `name` is required.
+	pub fn validate(&self) -> Result<(), MemberValidationError> {
+		if let Patchable::Patched(name) = &self.name {
+			validate_name(name)?;
+		}
+
+		if let Patchable::Patched(proxy_tags) = &self.proxy_tags {
+			validate_proxy_tags(proxy_tags)?;
+		}
+
+		if let Patchable::Patched(Some(birthday)) = &self.birthday {
+			validate_birthday(birthday)?;
+		}
+
+		Ok(())
+	}
+}
+
+fn validate_name(name: &str) -> Result<(), MemberValidationError> {
+	// PluralKit trims names, so a name of only whitespace is just as empty.
+	match name.trim().is_empty() {
+		true => Err(MemberValidationError::EmptyName),
+		false => Ok(()),
+	}
+}
+
+fn validate_proxy_tags(proxy_tags: &[ProxyTag]) -> Result<(), MemberValidationError> {
+	// Empty tags are dropped when serializing, so they don't count towards the limit.
+	let count = proxy_tags.iter().filter(|tag| !tag.is_empty()).count();
+
+	match count > PROXY_TAG_COUNT_LIMIT {
+		true => Err(MemberValidationError::ProxyTagCountExceeded(count)),
+		false => Ok(()),
+	}
+}
+
+/// Year PluralKit uses for birthdays with a hidden year.
+const HIDDEN_BIRTHDAY_YEAR: i32 = 4;
+
+/// Rejects birthdays in years which are likely a mistake, before year 1 or after next year, other than the year used
+/// for hidden years.
+fn validate_birthday(birthday: &OffsetDateTime) -> Result<(), MemberValidationError> {
+	let year = birthday.year();
+
+	if year == HIDDEN_BIRTHDAY_YEAR {
+		return Ok(());
+	}
+
+	match year < 1 || year > OffsetDateTime::now_utc().year() + 1 {
+		true => Err(MemberValidationError::ImplausibleBirthdayYear(year)),
+		false => Ok(()),
+	}
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum MemberValidationError {
+	#[error("name must not be empty")]
+	EmptyName,
+	#[error("a member may have at most 100 proxy tags, but {0} were given")]
+	ProxyTagCountExceeded(usize),
+	#[error("birthday year {0} is implausible, use 0004 to hide the year")]
+	ImplausibleBirthdayYear(i32),
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(default))]
 pub struct MemberPrivacyPatch {
 	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
 	pub visibility: Patchable<Privacy>,
@@ -152,6 +932,30 @@ impl MemberPrivacyPatch {
 	pub const PUBLIC: MemberPrivacyPatch = Self::all(Privacy::Public);
 	pub const PRIVATE: MemberPrivacyPatch = Self::all(Privacy::Private);
 
+	/// Combines two patches, fields modified in `other` take priority over those in `self`.
+	pub fn merge(self, other: MemberPrivacyPatch) -> MemberPrivacyPatch {
+		MemberPrivacyPatch {
+			visibility: other.visibility.or(self.visibility),
+			name: other.name.or(self.name),
+			description: other.description.or(self.description),
+			birthday: other.birthday.or(self.birthday),
+			pronouns: other.pronouns.or(self.pronouns),
+			avatar: other.avatar.or(self.avatar),
+			metadata: other.metadata.or(self.metadata),
+		}
+	}
+
+	/// Whether applying the patch to `privacy` would leave it unchanged.
+	pub fn is_noop_against(&self, privacy: &MemberPrivacy) -> bool {
+		self.visibility.is_noop_against(&privacy.visibility)
+			&& self.name.is_noop_against(&privacy.name)
+			&& self.description.is_noop_against(&privacy.description)
+			&& self.birthday.is_noop_against(&privacy.birthday)
+			&& self.pronouns.is_noop_against(&privacy.pronouns)
+			&& self.avatar.is_noop_against(&privacy.avatar)
+			&& self.metadata.is_noop_against(&privacy.metadata)
+	}
+
 	const fn all(privacy: Privacy) -> MemberPrivacyPatch {
 		MemberPrivacyPatch {
 			visibility: Patchable::Patched(privacy),
@@ -1,18 +1,91 @@
 pub mod member;
+pub mod switch;
+pub mod system;
 
-use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
+use serde::{ser, Deserialize, Serialize, Serializer};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
 
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
 pub enum Privacy {
 	Public,
 	Private,
+	/// A privacy level this version of the library doesn't know about, so that new levels added by PluralKit don't
+	/// break deserialization. The original value isn't kept, so it can't be sent back to PluralKit, serializing it is an
+	/// error.
+	#[serde(other)]
+	Unknown,
 }
 
+impl Serialize for Privacy {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self {
+			Privacy::Public => serializer.serialize_str("public"),
+			Privacy::Private => serializer.serialize_str("private"),
+			Privacy::Unknown => Err(ser::Error::custom(
+				"unknown privacy level should not be sent to PluralKit",
+			)),
+		}
+	}
+}
+
+/// Displays the privacy level as it is sent to PluralKit, which, other than `Unknown`, can be parsed back with `FromStr`.
+impl Display for Privacy {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Privacy::Public => "public",
+			Privacy::Private => "private",
+			Privacy::Unknown => "unknown",
+		})
+	}
+}
+
+/// Parses a privacy level from user input, such as a command argument, accepting "public"/"pub" and "private"/"priv"
+/// in any case.
+impl<'a> TryFrom<&'a str> for Privacy {
+	type Error = PrivacyParseError;
+
+	fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+		match value.to_ascii_lowercase().as_str() {
+			"public" | "pub" => Ok(Privacy::Public),
+			"private" | "priv" => Ok(Privacy::Private),
+			_ => Err(PrivacyParseError(value.into())),
+		}
+	}
+}
+
+impl FromStr for Privacy {
+	type Err = PrivacyParseError;
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		Self::try_from(value)
+	}
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("\"{0}\" is not a privacy level, expected \"public\" or \"private\"")]
+pub struct PrivacyParseError(Box<str>);
+
+/// Error returned when converting a `serde_json::Value` into one of the models fails.
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct FromValueError(#[from] serde_json::Error);
+
+/// Marker which may be returned in place of a field hidden by privacy settings. This isn't part of PluralKit's
+/// documented API, which omits or nulls hidden fields, it is only accepted defensively by the fields known to be
+/// affected by privacy, color and birthday, which deserialize it to `None`.
+const PRIVACY_SENTINEL: &str = "@unknown";
+
 // I have reinvented Option<T>
-#[derive(Clone, Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub enum Patchable<T: Clone + Debug + Serialize> {
+	/// Set the field to this value, for optional fields `Patched(None)` clears the field.
 	Patched(T),
+	/// Leave the field as it is, the field is omitted from the request entirely.
 	#[default]
 	Unmodified,
 }
@@ -24,9 +97,94 @@ impl<T: Clone + Debug + Serialize> Patchable<T> {
 			Patchable::Unmodified => true,
 		}
 	}
+
+	/// Whether applying this to a field with the value `current` would leave it unchanged.
+	fn is_noop_against(&self, current: &T) -> bool
+	where
+		T: PartialEq,
+	{
+		match self {
+			Patchable::Patched(value) => value == current,
+			Patchable::Unmodified => true,
+		}
+	}
+
+	/// `Some(value)` becomes `Patched(value)`, and `None` becomes `Unmodified`. Note that this means `None` leaves the
+	/// field untouched, to clear an optional field use `Patchable::clear`.
+	pub fn from_option(option: Option<T>) -> Patchable<T> {
+		match option {
+			Some(value) => Patchable::Patched(value),
+			None => Patchable::Unmodified,
+		}
+	}
+
+	pub fn into_option(self) -> Option<T> {
+		match self {
+			Patchable::Patched(value) => Some(value),
+			Patchable::Unmodified => None,
+		}
+	}
+
+	pub fn and_then<U, F>(self, f: F) -> Patchable<U>
+	where
+		U: Clone + Debug + Serialize,
+		F: FnOnce(T) -> Patchable<U>,
+	{
+		match self {
+			Patchable::Patched(value) => f(value),
+			Patchable::Unmodified => Patchable::Unmodified,
+		}
+	}
+
+	pub fn or(self, other: Patchable<T>) -> Patchable<T> {
+		match self {
+			Patchable::Patched(value) => Patchable::Patched(value),
+			Patchable::Unmodified => other,
+		}
+	}
+
+	/// Takes the value out, leaving `Unmodified` in its place.
+	pub fn take(&mut self) -> Patchable<T> {
+		std::mem::take(self)
+	}
+
+	/// Replaces the value with `Patched(value)`, returning the previous value.
+	pub fn replace(&mut self, value: T) -> Patchable<T> {
+		std::mem::replace(self, Patchable::Patched(value))
+	}
+}
+
+impl<T: Clone + Debug + Serialize> Patchable<Option<T>> {
+	/// Clears the field, which is sent as `null`, as opposed to `Unmodified` which leaves the field untouched.
+	pub const fn clear() -> Self {
+		Patchable::Patched(None)
+	}
+}
+
+impl<T: Clone + Debug + Serialize> Serialize for Patchable<T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		patchable::serialize_with(self, serializer, T::serialize)
+	}
+}
+
+/// The schema of a `Patchable` is that of the value, as `Unmodified` fields are omitted rather than serialized.
+#[cfg(feature = "schemars")]
+impl<T: Clone + Debug + Serialize + schemars::JsonSchema> schemars::JsonSchema for Patchable<T> {
+	fn is_referenceable() -> bool {
+		false
+	}
+
+	fn schema_name() -> String {
+		format!("Patchable_{}", T::schema_name())
+	}
+
+	fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		gen.subschema_for::<T>()
+	}
 }
 
 mod color {
+	use crate::models::PRIVACY_SENTINEL;
 	use rgb::RGB8;
 	use serde::{de, Deserialize, Deserializer, Serializer};
 
@@ -40,50 +198,226 @@ mod color {
 		}
 	}
 
+	/// Schema of an optional color, only the hex form PluralKit uses is described.
+	#[cfg(feature = "schemars")]
+	pub fn schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		use schemars::schema::{InstanceType, SchemaObject, StringValidation};
+
+		SchemaObject {
+			instance_type: Some(vec![InstanceType::String, InstanceType::Null].into()),
+			string: Some(Box::new(StringValidation {
+				pattern: Some("^[0-9a-fA-F]{6}$".into()),
+				..Default::default()
+			})),
+			..Default::default()
+		}
+		.into()
+	}
+
+	/// PluralKit uses hex strings, but other sources may use an object of the components, so both are accepted.
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum Color {
+		Hex(String),
+		Components(RGB8),
+	}
+
 	pub fn deserialize<'d, D: Deserializer<'d>>(deserializer: D) -> Result<Option<RGB8>, D::Error> {
-		let hex = match Option::<&str>::deserialize(deserializer)? {
-			None => return Ok(None),
-			Some(value) => value,
+		let hex = match Option::<Color>::deserialize(deserializer)? {
+			// Some responses use an empty string rather than null for no color.
+			Some(Color::Hex(value)) if value != PRIVACY_SENTINEL && !value.trim().is_empty() => {
+				value
+			}
+			Some(Color::Components(color)) => return Ok(Some(color)),
+			_ => return Ok(None),
 		};
 
-		let values = hex::decode(hex).map_err(de::Error::custom)?;
+		// PluralKit colors don't have an alpha channel, but colors from other sources might, in which case it's dropped.
+		match hex::decode(&hex).map_err(de::Error::custom)?[..] {
+			[r, g, b] | [r, g, b, _] => Ok(Some(RGB8::new(r, g, b))),
+			_ => Err(de::Error::custom(format!(
+				"color \"{hex}\" should be 6 or 8 hex digits"
+			))),
+		}
+	}
+}
+
+mod count {
+	use serde::{de, Deserialize, Deserializer};
+
+	/// PluralKit has sent counts as both numbers and numeric strings, so both are accepted.
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum Count {
+		Number(u32),
+		String(String),
+	}
 
-		Ok(Some(RGB8::new(values[0], values[1], values[2])))
+	pub fn deserialize<'d, D: Deserializer<'d>>(deserializer: D) -> Result<Option<u32>, D::Error> {
+		match Option::<Count>::deserialize(deserializer)? {
+			Some(Count::Number(count)) => Ok(Some(count)),
+			Some(Count::String(count)) => count
+				.trim()
+				.parse()
+				.map(Some)
+				.map_err(|_| de::Error::custom(format!("count \"{count}\" should be a number"))),
+			None => Ok(None),
+		}
 	}
 }
 
-mod patchable_color {
-	use crate::models::{color, Patchable};
-	use rgb::RGB8;
-	use serde::{ser::Error, Serializer};
+mod datetime {
+	use crate::models::PRIVACY_SENTINEL;
+	use serde::{de, Deserialize, Deserializer};
+	use time::{format_description::well_known::Iso8601, Date, OffsetDateTime};
+
+	/// Accepts either a full ISO 8601 datetime, or a date such as PluralKit uses for birthdays (example: "2000-01-01"),
+	/// which becomes midnight UTC on that date.
+	pub fn deserialize<'d, D: Deserializer<'d>>(
+		deserializer: D,
+	) -> Result<Option<OffsetDateTime>, D::Error> {
+		let datetime = match Option::<String>::deserialize(deserializer)? {
+			Some(value) if value != PRIVACY_SENTINEL => value,
+			_ => return Ok(None),
+		};
+
+		if let Ok(datetime) = OffsetDateTime::parse(&datetime, &Iso8601::DEFAULT) {
+			return Ok(Some(datetime));
+		}
+
+		Date::parse(&datetime, &Iso8601::DEFAULT)
+			.map(|date| Some(date.midnight().assume_utc()))
+			.map_err(de::Error::custom)
+	}
+
+	/// Schema of an optional ISO 8601 datetime.
+	#[cfg(feature = "schemars")]
+	pub fn schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		use schemars::schema::{InstanceType, SchemaObject};
+
+		SchemaObject {
+			instance_type: Some(vec![InstanceType::String, InstanceType::Null].into()),
+			format: Some("date-time".into()),
+			..Default::default()
+		}
+		.into()
+	}
+}
+
+/// PluralKit stores birthdays as dates, so only the date is sent, while any time is ignored. (example: "2000-01-01")
+mod birthday {
+	use serde::Serializer;
+	use time::OffsetDateTime;
 
 	pub fn serialize<S: Serializer>(
-		color: &Patchable<Option<RGB8>>,
+		birthday: &Option<OffsetDateTime>,
 		serializer: S,
 	) -> Result<S::Ok, S::Error> {
-		match color {
-			Patchable::Patched(color) => color::serialize(color, serializer),
-			Patchable::Unmodified => Err(Error::custom(
-				"unmodified patchable should not be serialized",
+		match birthday {
+			None => serializer.serialize_none(),
+			Some(birthday) => serializer.serialize_str(&format!(
+				"{:04}-{:02}-{:02}",
+				birthday.year(),
+				u8::from(birthday.month()),
+				birthday.day()
 			)),
 		}
 	}
+
+	/// Schema of an optional date.
+	#[cfg(feature = "schemars")]
+	pub fn schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		use schemars::schema::{InstanceType, SchemaObject};
+
+		SchemaObject {
+			instance_type: Some(vec![InstanceType::String, InstanceType::Null].into()),
+			format: Some("date".into()),
+			..Default::default()
+		}
+		.into()
+	}
 }
 
-mod patchable_datetime {
+/// Shared handling for serializing `Patchable` fields with a custom serializer for the inner value, `Unmodified` fields
+/// are expected to be skipped using `Patchable::is_unmodified`, so serializing one is an error.
+mod patchable {
 	use crate::models::Patchable;
-	use serde::{ser::Error, Serializer};
-	use time::{serde::iso8601, OffsetDateTime};
+	use serde::{ser::Error, Serialize, Serializer};
+	use std::fmt::Debug;
 
-	pub fn serialize<S: Serializer>(
-		datetime: &Patchable<Option<OffsetDateTime>>,
+	pub fn serialize_with<T, S, F>(
+		patchable: &Patchable<T>,
 		serializer: S,
-	) -> Result<S::Ok, S::Error> {
-		match datetime {
-			Patchable::Patched(datetime) => iso8601::option::serialize(datetime, serializer),
+		serialize: F,
+	) -> Result<S::Ok, S::Error>
+	where
+		T: Clone + Debug + Serialize,
+		S: Serializer,
+		F: FnOnce(&T, S) -> Result<S::Ok, S::Error>,
+	{
+		match patchable {
+			Patchable::Patched(value) => serialize(value, serializer),
 			Patchable::Unmodified => Err(Error::custom(
 				"unmodified patchable should not be serialized",
 			)),
 		}
 	}
 }
+
+mod patchable_color {
+	use crate::models::{color, patchable, Patchable};
+	use rgb::RGB8;
+	use serde::Serializer;
+
+	pub fn serialize<S: Serializer>(
+		color: &Patchable<Option<RGB8>>,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		patchable::serialize_with(color, serializer, color::serialize)
+	}
+}
+
+mod patchable_birthday {
+	use crate::models::{birthday, patchable, Patchable};
+	use serde::Serializer;
+	use time::OffsetDateTime;
+
+	pub fn serialize<S: Serializer>(
+		birthday: &Patchable<Option<OffsetDateTime>>,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		patchable::serialize_with(birthday, serializer, birthday::serialize)
+	}
+}
+
+mod proxy_tags {
+	use crate::models::member::ProxyTag;
+	use serde::Serializer;
+
+	/// Empty proxy tags are dropped rather than sent, as PluralKit would reject the whole request because of them.
+	pub fn serialize<S: Serializer>(
+		proxy_tags: &[ProxyTag],
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(proxy_tags.iter().filter(|tag| !tag.is_empty()))
+	}
+
+	/// Whether there would be nothing to send once empty proxy tags are dropped.
+	pub fn is_empty(proxy_tags: &[ProxyTag]) -> bool {
+		proxy_tags.iter().all(ProxyTag::is_empty)
+	}
+}
+
+mod patchable_proxy_tags {
+	use crate::models::{member::ProxyTag, patchable, Patchable};
+	use serde::Serializer;
+
+	pub fn serialize<S: Serializer>(
+		proxy_tags: &Patchable<Vec<ProxyTag>>,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		patchable::serialize_with(proxy_tags, serializer, |proxy_tags, serializer| {
+			crate::models::proxy_tags::serialize(proxy_tags, serializer)
+		})
+	}
+}
@@ -0,0 +1,81 @@
+use crate::models::member::Member;
+use crate::references::ShortId;
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+/// Switches are ordered chronologically, by timestamp and then by uuid, so the order is deterministic even for switches
+/// with the same timestamp.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Switch {
+	pub id: Uuid,
+	#[serde(with = "time::serde::iso8601")]
+	pub timestamp: OffsetDateTime,
+	pub members: Vec<ShortId>,
+}
+
+impl Switch {
+	/// Resolves the members of this switch from an already fetched list of members, avoiding a request per member. The
+	/// order of the switch is preserved, with `None` for any member not present in `members`.
+	pub fn resolve_members<'a>(&self, members: &'a [Member]) -> Vec<Option<&'a Member>> {
+		self.members
+			.iter()
+			.map(|id| members.iter().find(|member| &member.id == id))
+			.collect()
+	}
+}
+
+impl Ord for Switch {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.timestamp
+			.cmp(&other.timestamp)
+			.then_with(|| self.id.cmp(&other.id))
+			.then_with(|| self.members.cmp(&other.members))
+	}
+}
+
+impl PartialOrd for Switch {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+/// Sorts switches chronologically and removes duplicates by uuid, for stitching together overlapping pages of switch
+/// history. Where a switch appears more than once, the earliest is kept.
+pub fn dedup_switches(mut switches: Vec<Switch>) -> Vec<Switch> {
+	switches.sort();
+
+	let mut seen = HashSet::new();
+	switches.retain(|switch| seen.insert(switch.id));
+
+	switches
+}
+
+/// Computes how long each member was fronting across `switches`, each switch lasting until the next one, and the most
+/// recent one lasting until `now`. Members are returned in the order they first fronted, with the durations of all
+/// their fronts added together. If `now` is before the most recent switch, such as due to clock skew, that switch is
+/// counted as lasting no time rather than a negative duration.
+pub fn front_durations(switches: &[Switch], now: OffsetDateTime) -> Vec<(ShortId, Duration)> {
+	let mut switches: Vec<_> = switches.iter().collect();
+	switches.sort_by_key(|switch| switch.timestamp);
+
+	let mut durations: Vec<(ShortId, Duration)> = Vec::new();
+
+	for (index, switch) in switches.iter().enumerate() {
+		let end = switches
+			.get(index + 1)
+			.map_or(now.max(switch.timestamp), |next| next.timestamp);
+		let duration = end - switch.timestamp;
+
+		for member in &switch.members {
+			match durations.iter_mut().find(|(id, _)| id == member) {
+				Some((_, total)) => *total += duration,
+				None => durations.push((member.clone(), duration)),
+			}
+		}
+	}
+
+	durations
+}
@@ -0,0 +1,219 @@
+use crate::limited::{LimitedStr, LimitedUrl};
+#[cfg(feature = "serde_json")]
+use crate::models::FromValueError;
+use crate::models::{Patchable, Privacy};
+use crate::references::ShortId;
+use rgb::RGB8;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Maximum length of a system tag, as enforced by PluralKit.
+pub const SYSTEM_TAG_LIMIT: usize = 79;
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct System {
+	pub id: ShortId,
+	pub uuid: Uuid,
+	pub name: Option<LimitedStr<100>>,
+	pub description: Option<LimitedStr<1000>>,
+	pub tag: Option<LimitedStr<SYSTEM_TAG_LIMIT>>,
+	pub pronouns: Option<LimitedStr<100>>,
+	#[serde(rename = "avatar_url")]
+	pub avatar: Option<LimitedUrl<256>>,
+	pub banner: Option<LimitedUrl<256>>,
+	#[serde(with = "crate::models::color")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::color::schema")
+	)]
+	pub color: Option<RGB8>,
+	#[serde(with = "time::serde::iso8601::option")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::datetime::schema")
+	)]
+	pub created: Option<OffsetDateTime>,
+	/// Only present when the member list is visible to you.
+	#[serde(default)]
+	#[serde(deserialize_with = "crate::models::count::deserialize")]
+	pub member_count: Option<u32>,
+	/// Only present when the group list is visible to you.
+	#[serde(default)]
+	#[serde(deserialize_with = "crate::models::count::deserialize")]
+	pub group_count: Option<u32>,
+	pub privacy: Option<SystemPrivacy>,
+}
+
+impl System {
+	/// Returns the tag as it would be appended to proxied messages, `None` if there is no tag or it is only whitespace.
+	pub fn effective_tag(&self) -> Option<&str> {
+		self.tag
+			.as_deref()
+			.map(str::trim)
+			.filter(|tag| !tag.is_empty())
+	}
+
+	/// Returns a copy of the system with the fields its privacy settings don't make public cleared, along with the
+	/// privacy settings themselves, approximating how the system appears when fetched by anyone else. Fields with an
+	/// `Unknown` privacy level are also cleared. Only the system itself receives the privacy settings, and PluralKit has
+	/// already omitted the private fields for anyone else, so when they are absent the system is returned unchanged.
+	pub fn redacted(&self) -> System {
+		let mut system = self.clone();
+
+		let Some(privacy) = system.privacy.take() else {
+			return system;
+		};
+
+		if privacy.description != Privacy::Public {
+			system.description = None;
+		}
+
+		if privacy.pronouns != Privacy::Public {
+			system.pronouns = None;
+		}
+
+		if privacy.member_list != Privacy::Public {
+			system.member_count = None;
+		}
+
+		if privacy.group_list != Privacy::Public {
+			system.group_count = None;
+		}
+
+		system
+	}
+
+	/// Returns the member and group counts, treating counts which are hidden or weren't requested as 0.
+	pub fn counts(&self) -> (u32, u32) {
+		(
+			self.member_count.unwrap_or_default(),
+			self.group_count.unwrap_or_default(),
+		)
+	}
+}
+
+#[cfg(feature = "serde_json")]
+impl TryFrom<serde_json::Value> for System {
+	type Error = FromValueError;
+
+	fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+		Ok(serde_json::from_value(value)?)
+	}
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SystemPrivacy {
+	#[serde(rename = "description_privacy")]
+	pub description: Privacy,
+	#[serde(rename = "pronoun_privacy")]
+	pub pronouns: Privacy,
+	#[serde(rename = "member_list_privacy")]
+	pub member_list: Privacy,
+	#[serde(rename = "group_list_privacy")]
+	pub group_list: Privacy,
+	#[serde(rename = "front_privacy")]
+	pub front: Privacy,
+	#[serde(rename = "front_history_privacy")]
+	pub front_history: Privacy,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(default))]
+pub struct SystemPatch {
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub name: Patchable<Option<LimitedStr<100>>>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub description: Patchable<Option<LimitedStr<1000>>>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub tag: Patchable<Option<LimitedStr<SYSTEM_TAG_LIMIT>>>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub pronouns: Patchable<Option<LimitedStr<100>>>,
+	#[serde(rename = "avatar_url")]
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub avatar: Patchable<Option<LimitedUrl<256>>>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub banner: Patchable<Option<LimitedUrl<256>>>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	#[serde(with = "crate::models::patchable_color")]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(schema_with = "crate::models::color::schema")
+	)]
+	pub color: Patchable<Option<RGB8>>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub privacy: Patchable<SystemPrivacyPatch>,
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(default))]
+pub struct SystemPrivacyPatch {
+	#[serde(rename = "description_privacy")]
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub description: Patchable<Privacy>,
+	#[serde(rename = "pronoun_privacy")]
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub pronouns: Patchable<Privacy>,
+	#[serde(rename = "member_list_privacy")]
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub member_list: Patchable<Privacy>,
+	#[serde(rename = "group_list_privacy")]
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub group_list: Patchable<Privacy>,
+	#[serde(rename = "front_privacy")]
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub front: Patchable<Privacy>,
+	#[serde(rename = "front_history_privacy")]
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub front_history: Patchable<Privacy>,
+}
+
+impl SystemPrivacyPatch {
+	pub const PUBLIC: SystemPrivacyPatch = Self::all(Privacy::Public);
+	pub const PRIVATE: SystemPrivacyPatch = Self::all(Privacy::Private);
+
+	const fn all(privacy: Privacy) -> SystemPrivacyPatch {
+		SystemPrivacyPatch {
+			description: Patchable::Patched(privacy),
+			pronouns: Patchable::Patched(privacy),
+			member_list: Patchable::Patched(privacy),
+			group_list: Patchable::Patched(privacy),
+			front: Patchable::Patched(privacy),
+			front_history: Patchable::Patched(privacy),
+		}
+	}
+}
+
+/// Settings of the authenticated system, from `/systems/@me/settings`.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SystemSettings {
+	/// Name of a timezone from the tz database. (example: "Europe/London")
+	pub timezone: Box<str>,
+	pub pings_enabled: bool,
+	pub member_default_private: bool,
+	pub group_default_private: bool,
+	pub show_private_info: bool,
+	pub member_limit: u32,
+	pub group_limit: u32,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(default))]
+pub struct SystemSettingsPatch {
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub timezone: Patchable<Box<str>>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub pings_enabled: Patchable<bool>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub member_default_private: Patchable<bool>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub group_default_private: Patchable<bool>,
+	#[serde(skip_serializing_if = "Patchable::is_unmodified")]
+	pub show_private_info: Patchable<bool>,
+}
@@ -0,0 +1,24 @@
+//! Re-exports of the commonly used types, so they don't need to be imported individually from their modules.
+//!
+//! ```
+//! use plurallib::prelude::*;
+//!
+//! let id = ShortId::try_from("ptckn").unwrap();
+//! ```
+
+pub use crate::error::Error;
+pub use crate::limited::{
+	AvatarUrlError, ExceededLimitError, LimitedStr, LimitedUrl, LimitedUrlError,
+	OwnedExceededLimitError,
+};
+pub use crate::models::member::{
+	Member, MemberPatch, MemberPrivacy, MemberPrivacyPatch, MemberValidationError,
+	MemberVisibility, NewMember, ProxyTag, ProxyTagBuilder, ProxyTagComponent,
+	ProxyTagComponentError, ProxyTagError, ProxyTagExceededLimitError,
+};
+pub use crate::models::switch::Switch;
+pub use crate::models::system::{System, SystemPatch, SystemPrivacy, SystemPrivacyPatch};
+pub use crate::models::{Patchable, Privacy, PrivacyParseError};
+pub use crate::references::{
+	AsReference, GenericRef, GroupRef, Id, MemberRef, RefParseError, ShortError, ShortId, SystemRef,
+};
@@ -0,0 +1,64 @@
+use crate::models::member::{Member, ProxyTag};
+use std::cmp::Reverse;
+
+/// Matches message content against the proxy tags of a set of members, similarly to PluralKit, trying longer tags first
+/// so that a more specific tag wins over one it contains. Only content is considered, so a message which would be
+/// proxied only for its attachments, with nothing between the tags, doesn't match.
+///
+/// Building the matcher collects and sorts every proxy tag of the given members, which is O(n log n) in the total
+/// number of tags, so it should be built once and reused across messages rather than built per message.
+pub struct ProxyMatcher<'a> {
+	tags: Vec<(&'a Member, &'a ProxyTag)>,
+}
+
+impl<'a> ProxyMatcher<'a> {
+	pub fn new(members: &'a [Member]) -> Self {
+		let mut tags: Vec<_> = members
+			.iter()
+			.flat_map(|member| member.proxy_tags.iter().map(move |tag| (member, tag)))
+			// An empty tag would match every message.
+			.filter(|(_, tag)| !tag.is_empty())
+			.collect();
+
+		tags.sort_by_key(|(_, tag)| Reverse(tag_length(tag)));
+
+		Self { tags }
+	}
+
+	/// Returns the member whose proxy tags match `content`, along with the content with the tags removed. Tags with
+	/// nothing but whitespace between them don't match, as there would be nothing to proxy.
+	pub fn match_message(&self, content: &str) -> Option<(&'a Member, String)> {
+		self.tags.iter().find_map(|(member, tag)| {
+			let content = content.strip_prefix(tag.prefix.as_deref().unwrap_or_default())?;
+			let content = content.strip_suffix(tag.suffix.as_deref().unwrap_or_default())?;
+
+			match content.trim().is_empty() {
+				true => None,
+				false => Some((*member, content.to_string())),
+			}
+		})
+	}
+
+	/// Lazily matches each of `messages`, see `match_message`.
+	pub fn match_messages<'s, I>(
+		&'s self,
+		messages: I,
+	) -> impl Iterator<Item = Option<(&'a Member, String)>> + 's
+	where
+		I: IntoIterator,
+		I::IntoIter: 's,
+		I::Item: AsRef<str>,
+	{
+		messages
+			.into_iter()
+			.map(|content| self.match_message(content.as_ref()))
+	}
+}
+
+fn tag_length(tag: &ProxyTag) -> usize {
+	[&tag.prefix, &tag.suffix]
+		.into_iter()
+		.flatten()
+		.map(|component| component.chars().count())
+		.sum()
+}
@@ -1,4 +1,5 @@
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::fmt::{self, Display, Formatter};
 use std::ops::Deref;
 use thiserror::Error;
 use uuid::Uuid;
@@ -14,8 +15,7 @@ use uuid::Uuid;
 /// This format is expected to change to 6 characters, with an optional `-` in the middle, with old ids remaining valid.
 /// When this happens, the library will be updated, while this should not be a breaking change, however you have been
 /// warned.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(try_from = "&str")]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct ShortId(Box<str>);
 
 impl Deref for ShortId {
@@ -26,9 +26,32 @@ impl Deref for ShortId {
 	}
 }
 
-impl ToString for ShortId {
-	fn to_string(&self) -> String {
-		self.0.to_string()
+impl Display for ShortId {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+impl ShortId {
+	/// Creates a `ShortId` from raw bytes, validating them in the same way as `TryFrom<&str>`.
+	pub fn from_bytes(bytes: &[u8]) -> Result<ShortId, ShortError> {
+		if bytes.len() != 5 {
+			return Err(ShortError::IncorrectLength);
+		}
+
+		if !bytes.iter().all(u8::is_ascii_lowercase) {
+			return Err(ShortError::InvalidCharacters);
+		}
+
+		// Only ascii is accepted above, so this can't fail.
+		let value = std::str::from_utf8(bytes).map_err(|_| ShortError::InvalidCharacters)?;
+
+		Ok(ShortId(value.into()))
+	}
+
+	/// Trims surrounding whitespace, including zero width characters, before parsing. Useful for ids copied from chat.
+	pub fn parse_trimmed(value: &str) -> Result<ShortId, ShortError> {
+		Self::try_from(trim(value))
 	}
 }
 
@@ -36,26 +59,88 @@ impl<'a> TryFrom<&'a str> for ShortId {
 	type Error = ShortError;
 
 	fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-		if value.len() != 5 {
-			return Err(ShortError::IncorrectLength);
-		}
+		let trimmed = trim(value);
 
-		for char in value.chars() {
-			if !char.is_ascii_lowercase() {
-				return Err(ShortError::InvalidCharacters);
-			}
+		if trimmed.len() != value.len() && Self::from_bytes(trimmed.as_bytes()).is_ok() {
+			return Err(ShortError::SurroundingWhitespace);
 		}
 
-		Ok(ShortId(value.into()))
+		Self::from_bytes(value.as_bytes())
 	}
 }
 
-#[derive(Debug, Eq, Error, PartialEq)]
+fn trim(value: &str) -> &str {
+	value.trim_matches(|char: char| {
+		char.is_whitespace() || matches!(char, '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{FEFF}')
+	})
+}
+
+impl<'de> Deserialize<'de> for ShortId {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let value = String::deserialize(deserializer)?;
+		Self::try_from(value.as_str()).map_err(de::Error::custom)
+	}
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ShortId {
+	fn schema_name() -> String {
+		"ShortId".into()
+	}
+
+	fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		use schemars::schema::{InstanceType, SchemaObject, StringValidation};
+
+		SchemaObject {
+			instance_type: Some(InstanceType::String.into()),
+			string: Some(Box::new(StringValidation {
+				pattern: Some("^[a-z]{5}$".into()),
+				..Default::default()
+			})),
+			..Default::default()
+		}
+		.into()
+	}
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
 pub enum ShortError {
 	#[error("A ShortId should only contain alphabetical characters (a-z)")]
 	InvalidCharacters,
 	#[error("A ShortId should only be 5 characters in length")]
 	IncorrectLength,
+	#[error("A ShortId should not be surrounded by whitespace, try trimming it")]
+	SurroundingWhitespace,
+}
+
+/// An id which may be either a `ShortId` or a `Uuid`, for storing ids of either kind, such as the keys of a cache.
+/// Unlike `GenericRef` this can be serialized, as either id's string form. When deserializing, a `Uuid` is tried first.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Id {
+	Uuid(Uuid),
+	Short(ShortId),
+}
+
+impl Display for Id {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Id::Uuid(uuid) => Display::fmt(uuid, f),
+			Id::Short(short) => Display::fmt(short, f),
+		}
+	}
+}
+
+impl From<ShortId> for Id {
+	fn from(value: ShortId) -> Self {
+		Self::Short(value)
+	}
+}
+
+impl From<Uuid> for Id {
+	fn from(value: Uuid) -> Self {
+		Self::Uuid(value)
+	}
 }
 
 /// This represents a reference to a Member or Group. This can either be a `ShortId` or a `Uuid`. Note that `Ref` is not
@@ -69,20 +154,77 @@ pub enum GenericRef {
 	Uuid(Uuid),
 }
 
-impl ToString for GenericRef {
-	fn to_string(&self) -> String {
+impl Display for GenericRef {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		match self {
-			GenericRef::ShortId(short) => short.to_string(),
-			GenericRef::Uuid(uuid) => uuid.to_string(),
+			GenericRef::ShortId(short) => Display::fmt(short, f),
+			GenericRef::Uuid(uuid) => Display::fmt(uuid, f),
 		}
 	}
 }
 
+/// Parses the reference as a `Uuid` if possible, otherwise as a `ShortId`.
 impl<'a> TryFrom<&'a str> for GenericRef {
-	type Error = ShortError;
+	type Error = RefParseError;
 
 	fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-		Ok(GenericRef::ShortId(ShortId::try_from(value)?))
+		let uuid = match Uuid::try_parse(value) {
+			Ok(uuid) => return Ok(GenericRef::Uuid(uuid)),
+			Err(error) => error,
+		};
+
+		match ShortId::try_from(value) {
+			Ok(short) => Ok(GenericRef::ShortId(short)),
+			Err(short) => Err(RefParseError::Invalid { uuid, short }),
+		}
+	}
+}
+
+/// Values which can be used to reference a member or group, so functions taking a reference can accept any of them
+/// rather than requiring a `GenericRef`. Strings are parsed as in `GenericRef::try_from`, which is why this is fallible.
+pub trait AsReference {
+	fn as_reference(&self) -> Result<GenericRef, RefParseError>;
+}
+
+impl<T: AsReference + ?Sized> AsReference for &T {
+	fn as_reference(&self) -> Result<GenericRef, RefParseError> {
+		(**self).as_reference()
+	}
+}
+
+impl AsReference for GenericRef {
+	fn as_reference(&self) -> Result<GenericRef, RefParseError> {
+		Ok(match self {
+			GenericRef::ShortId(short) => GenericRef::ShortId(short.clone()),
+			GenericRef::Uuid(uuid) => GenericRef::Uuid(*uuid),
+		})
+	}
+}
+
+impl AsReference for Id {
+	fn as_reference(&self) -> Result<GenericRef, RefParseError> {
+		Ok(match self {
+			Id::Short(short) => GenericRef::ShortId(short.clone()),
+			Id::Uuid(uuid) => GenericRef::Uuid(*uuid),
+		})
+	}
+}
+
+impl AsReference for ShortId {
+	fn as_reference(&self) -> Result<GenericRef, RefParseError> {
+		Ok(GenericRef::ShortId(self.clone()))
+	}
+}
+
+impl AsReference for Uuid {
+	fn as_reference(&self) -> Result<GenericRef, RefParseError> {
+		Ok(GenericRef::Uuid(*self))
+	}
+}
+
+impl AsReference for str {
+	fn as_reference(&self) -> Result<GenericRef, RefParseError> {
+		GenericRef::try_from(self)
 	}
 }
 
@@ -92,6 +234,125 @@ impl From<Uuid> for GenericRef {
 	}
 }
 
+impl PartialEq<ShortId> for GenericRef {
+	fn eq(&self, other: &ShortId) -> bool {
+		matches!(self, GenericRef::ShortId(short) if short == other)
+	}
+}
+
+impl PartialEq<GenericRef> for ShortId {
+	fn eq(&self, other: &GenericRef) -> bool {
+		other == self
+	}
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum RefParseError {
+	#[error("A reference should be either a Uuid ({uuid}) or a ShortId ({short})")]
+	Invalid {
+		uuid: uuid::Error,
+		short: ShortError,
+	},
+	#[error("A mention should be in the form <@id> or <@!id>, where id is a Discord account id")]
+	InvalidMention,
+}
+
+/// This represents a reference to a Member, wrapping a `GenericRef` so that a reference to a group can't be mistakenly
+/// used where a reference to a member is expected.
+///
+/// ```compile_fail
+/// use plurallib::prelude::*;
+///
+/// fn delete_group(group: GroupRef) {}
+///
+/// delete_group(MemberRef::try_from("ptckn").unwrap());
+/// ```
+pub struct MemberRef(GenericRef);
+
+impl Deref for MemberRef {
+	type Target = GenericRef;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl Display for MemberRef {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&self.0, f)
+	}
+}
+
+impl<'a> TryFrom<&'a str> for MemberRef {
+	type Error = RefParseError;
+
+	fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+		Ok(MemberRef(GenericRef::try_from(value)?))
+	}
+}
+
+impl From<GenericRef> for MemberRef {
+	fn from(value: GenericRef) -> Self {
+		Self(value)
+	}
+}
+
+impl From<Uuid> for MemberRef {
+	fn from(value: Uuid) -> Self {
+		Self(GenericRef::Uuid(value))
+	}
+}
+
+impl AsReference for MemberRef {
+	fn as_reference(&self) -> Result<GenericRef, RefParseError> {
+		self.0.as_reference()
+	}
+}
+
+/// This represents a reference to a Group, wrapping a `GenericRef` so that a reference to a member can't be mistakenly
+/// used where a reference to a group is expected.
+pub struct GroupRef(GenericRef);
+
+impl Deref for GroupRef {
+	type Target = GenericRef;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl Display for GroupRef {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&self.0, f)
+	}
+}
+
+impl<'a> TryFrom<&'a str> for GroupRef {
+	type Error = RefParseError;
+
+	fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+		Ok(GroupRef(GenericRef::try_from(value)?))
+	}
+}
+
+impl From<GenericRef> for GroupRef {
+	fn from(value: GenericRef) -> Self {
+		Self(value)
+	}
+}
+
+impl From<Uuid> for GroupRef {
+	fn from(value: Uuid) -> Self {
+		Self(GenericRef::Uuid(value))
+	}
+}
+
+impl AsReference for GroupRef {
+	fn as_reference(&self) -> Result<GenericRef, RefParseError> {
+		self.0.as_reference()
+	}
+}
+
 /// This represents a reference to a System. This can either be a `ShortId`, `Uuid`, `Snowflake`, or `Current`. Note
 /// that `SystemRef` is not used for a reference to a group or member due to lacking reference types, so for that, see
 /// `GenericRef`.
@@ -108,22 +369,43 @@ pub enum SystemRef {
 	Current,
 }
 
-impl ToString for SystemRef {
-	fn to_string(&self) -> String {
+impl Display for SystemRef {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		match self {
-			SystemRef::ShortId(short) => short.to_string(),
-			SystemRef::Uuid(uuid) => uuid.to_string(),
-			SystemRef::Snowflake(snowflake) => snowflake.to_string(),
-			SystemRef::Current => String::from("@me"),
+			SystemRef::ShortId(short) => Display::fmt(short, f),
+			SystemRef::Uuid(uuid) => Display::fmt(uuid, f),
+			SystemRef::Snowflake(snowflake) => Display::fmt(snowflake, f),
+			SystemRef::Current => f.write_str("@me"),
 		}
 	}
 }
 
+impl SystemRef {
+	/// Parses a Discord user mention, in either the `<@id>` or `<@!id>` form, or a plain numeric id, into a reference to
+	/// the system of that Discord account.
+	pub fn from_mention(value: &str) -> Result<SystemRef, RefParseError> {
+		let id = value
+			.strip_prefix("<@")
+			.and_then(|value| value.strip_suffix('>'))
+			.map(|value| value.strip_prefix('!').unwrap_or(value))
+			.unwrap_or(value);
+
+		match id.parse() {
+			Ok(snowflake) => Ok(SystemRef::Snowflake(snowflake)),
+			Err(_) => Err(RefParseError::InvalidMention),
+		}
+	}
+}
+
+/// Parses the reference as a `Uuid` if possible, otherwise as a `ShortId`.
 impl<'a> TryFrom<&'a str> for SystemRef {
-	type Error = ShortError;
+	type Error = RefParseError;
 
 	fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-		Ok(SystemRef::ShortId(ShortId::try_from(value)?))
+		Ok(match GenericRef::try_from(value)? {
+			GenericRef::ShortId(short) => SystemRef::ShortId(short),
+			GenericRef::Uuid(uuid) => SystemRef::Uuid(uuid),
+		})
 	}
 }
 
@@ -138,3 +420,15 @@ impl From<u64> for SystemRef {
 		Self::Snowflake(value)
 	}
 }
+
+impl PartialEq<ShortId> for SystemRef {
+	fn eq(&self, other: &ShortId) -> bool {
+		matches!(self, SystemRef::ShortId(short) if short == other)
+	}
+}
+
+impl PartialEq<SystemRef> for ShortId {
+	fn eq(&self, other: &SystemRef) -> bool {
+		other == self
+	}
+}
@@ -0,0 +1,48 @@
+use crate::limited::{ExceededLimitError, OwnedExceededLimitError};
+use crate::models::member::ProxyTagExceededLimitError;
+use crate::references::ShortError;
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+use std::fmt::Display;
+
+/// Errors serialize as `{ "error": <code>, "message": <display> }`, where the code is stable for matching on while the
+/// message is intended for humans.
+fn serialize_error<S: Serializer>(
+	serializer: S,
+	error: &'static str,
+	message: &impl Display,
+) -> Result<S::Ok, S::Error> {
+	let mut state = serializer.serialize_struct("Error", 2)?;
+	state.serialize_field("error", error)?;
+	state.serialize_field("message", &message.to_string())?;
+	state.end()
+}
+
+impl Serialize for ShortError {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let error = match self {
+			ShortError::InvalidCharacters => "invalid_characters",
+			ShortError::IncorrectLength => "incorrect_length",
+			ShortError::SurroundingWhitespace => "surrounding_whitespace",
+		};
+
+		serialize_error(serializer, error, self)
+	}
+}
+
+impl Serialize for ExceededLimitError<'_> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_error(serializer, "exceeded_limit", self)
+	}
+}
+
+impl Serialize for OwnedExceededLimitError {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_error(serializer, "exceeded_limit", self)
+	}
+}
+
+impl Serialize for ProxyTagExceededLimitError {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_error(serializer, "proxy_tag_exceeded_limit", self)
+	}
+}
@@ -0,0 +1,8 @@
+use time::OffsetDateTime;
+
+/// Converts a datetime into milliseconds since the Unix epoch, such as for storing timestamps in a database. Any
+/// fraction of a millisecond is rounded down, including for datetimes before the epoch.
+pub fn to_unix_millis(datetime: &OffsetDateTime) -> i64 {
+	// OffsetDateTime is limited to years ±9999, which is well within the range of i64 milliseconds.
+	datetime.unix_timestamp_nanos().div_euclid(1_000_000) as i64
+}
@@ -0,0 +1,55 @@
+use plurallib::prelude::*;
+use rgb::RGB8;
+use serde_json::json;
+
+mod common;
+
+fn color(color: serde_json::Value) -> Result<Member, serde_json::Error> {
+	serde_json::from_value(common::with(
+		common::member_json(),
+		json!({ "color": color }),
+	))
+}
+
+#[test]
+fn hex_and_components_are_equal() {
+	let hex = color(json!("ff8000")).unwrap();
+	let components = color(json!({ "r": 255, "g": 128, "b": 0 })).unwrap();
+
+	assert_eq!(hex.color, Some(RGB8::new(0xff, 0x80, 0x00)));
+	assert_eq!(hex.color, components.color);
+}
+
+#[test]
+fn empty_color() {
+	assert_eq!(color(json!("")).unwrap().color, None);
+}
+
+#[test]
+fn null_color() {
+	assert_eq!(color(json!(null)).unwrap().color, None);
+}
+
+#[test]
+fn hex_color() {
+	assert_eq!(
+		color(json!("00ff7f")).unwrap().color,
+		Some(RGB8::new(0x00, 0xff, 0x7f))
+	);
+}
+
+#[test]
+fn color_of_wrong_length() {
+	let error = color(json!("ff80")).unwrap_err();
+
+	assert!(error.to_string().contains("6 or 8 hex digits"));
+}
+
+#[test]
+fn six_and_eight_digit_colors() {
+	let six = color(json!("ff8000")).unwrap();
+	let eight = color(json!("ff8000cc")).unwrap();
+
+	assert_eq!(six.color, Some(RGB8::new(0xff, 0x80, 0x00)));
+	assert_eq!(eight.color, six.color);
+}
@@ -0,0 +1,64 @@
+#![allow(dead_code)]
+
+use plurallib::prelude::*;
+use serde_json::{json, Value};
+
+/// A member as PluralKit would send it, with every optional field set to null.
+pub fn member_json() -> Value {
+	json!({
+		"id": "ptckn",
+		"uuid": "30523e4f-dd68-4b91-8ee0-59c7598db16c",
+		"system": "abcde",
+		"name": "Astra",
+		"display_name": null,
+		"color": null,
+		"birthday": null,
+		"pronouns": null,
+		"avatar_url": null,
+		"webhook_avatar_url": null,
+		"banner": null,
+		"description": null,
+		"created": null,
+		"proxy_tags": [],
+		"autoproxy_enabled": null,
+		"message_count": null,
+		"last_message_timestamp": null,
+		"privacy": null,
+	})
+}
+
+/// A system as PluralKit would send it, with every optional field set to null.
+pub fn system_json() -> Value {
+	json!({
+		"id": "abcde",
+		"uuid": "30523e4f-dd68-4b91-8ee0-59c7598db16c",
+		"name": "Astralchroma",
+		"description": null,
+		"tag": null,
+		"pronouns": null,
+		"avatar_url": null,
+		"banner": null,
+		"color": null,
+		"created": null,
+		"privacy": null,
+	})
+}
+
+/// Overwrites each field of `fields` on `base`.
+pub fn with(mut base: Value, fields: Value) -> Value {
+	for (key, value) in fields.as_object().unwrap() {
+		base[key] = value.clone();
+	}
+
+	base
+}
+
+/// Deserializes [`member_json`] with `fields` overwritten.
+pub fn member(fields: Value) -> Member {
+	serde_json::from_value(with(member_json(), fields)).unwrap()
+}
+
+/// Deserializes [`system_json`] with `fields` overwritten.
+pub fn system(fields: Value) -> System {
+	serde_json::from_value(with(system_json(), fields)).unwrap()
+}
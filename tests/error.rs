@@ -0,0 +1,39 @@
+use plurallib::prelude::*;
+
+fn parse<'a>(id: &'a str, avatar: &'a str) -> Result<(ShortId, LimitedUrl<256>), Error<'a>> {
+	Ok((ShortId::try_from(id)?, LimitedUrl::try_from(avatar)?))
+}
+
+#[test]
+fn question_mark_into_error() {
+	assert!(parse("ptckn", "https://example.com/avatar.png").is_ok());
+}
+
+#[test]
+fn short_id_into_error() {
+	assert_eq!(
+		parse("PTCKN", "https://example.com/avatar.png"),
+		Err(Error::Short(ShortError::InvalidCharacters))
+	);
+}
+
+#[test]
+fn limited_url_into_error() {
+	assert!(matches!(
+		parse("ptckn", "not a url"),
+		Err(Error::LimitedUrl(LimitedUrlError::ParseError(_)))
+	));
+}
+
+#[test]
+fn errors_are_clone() {
+	let short = ShortId::try_from("PTCKN").unwrap_err();
+	let limit = LimitedStr::<4>::try_from("Astra").unwrap_err();
+	let owned = limit.clone().into_owned();
+	let error = Error::from(limit.clone());
+
+	assert_eq!(short.clone(), short);
+	assert_eq!(limit.clone(), limit);
+	assert_eq!(owned.clone(), owned);
+	assert_eq!(error.clone(), error);
+}
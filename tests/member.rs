@@ -0,0 +1,287 @@
+use plurallib::models::member::{
+	members_in_group, members_with_id_prefix, members_with_name_prefix, sort_members_by_uuid,
+	MemberResponse,
+};
+use plurallib::prelude::*;
+use serde_json::json;
+use std::collections::HashSet;
+
+mod common;
+
+fn member_with_description(description: &str) -> Member {
+	common::member(json!({ "description": description }))
+}
+
+#[test]
+fn description_preview_fits() {
+	let member = member_with_description("hello world");
+
+	assert_eq!(
+		member.description_preview(11).as_deref(),
+		Some("hello world")
+	);
+}
+
+#[test]
+fn description_preview_cuts_mid_word() {
+	let member = member_with_description("hello world foo");
+
+	assert_eq!(member.description_preview(10).as_deref(), Some("hello…"));
+}
+
+#[test]
+fn description_preview_cuts_on_word_boundary() {
+	let member = member_with_description("hello world foo");
+
+	assert_eq!(
+		member.description_preview(12).as_deref(),
+		Some("hello world…")
+	);
+}
+
+#[test]
+fn description_preview_counts_chars() {
+	let member = member_with_description("héllo wörld foo");
+
+	assert_eq!(
+		member.description_preview(12).as_deref(),
+		Some("héllo wörld…")
+	);
+}
+
+#[test]
+fn birthday_date() {
+	let member = common::member(json!({ "birthday": "2000-01-02" }));
+	let birthday = member.birthday.unwrap();
+
+	assert_eq!(
+		(birthday.year(), u8::from(birthday.month()), birthday.day()),
+		(2000, 1, 2)
+	);
+}
+
+#[test]
+fn birthday_hidden_year() {
+	let member = common::member(json!({ "birthday": "0004-01-02" }));
+
+	assert_eq!(member.birthday.unwrap().year(), 4);
+}
+
+#[test]
+fn birthday_privacy_sentinel() {
+	let member = common::member(json!({ "birthday": "@unknown" }));
+
+	assert_eq!(member.birthday, None);
+}
+
+#[test]
+fn color_privacy_sentinel() {
+	let member = common::member(json!({ "color": "@unknown" }));
+
+	assert_eq!(member.color, None);
+}
+
+#[test]
+fn members_with_prefix() {
+	let members = [
+		common::member(json!({ "id": "abcde", "name": "Luna" })),
+		common::member(json!({ "id": "abxyz", "name": "Astrid" })),
+		common::member(json!({ "id": "qwert", "name": "astra" })),
+		common::member(json!({ "id": "zyxwv", "name": "Robin" })),
+	];
+
+	let by_id: Vec<&str> = {
+		let query = String::from("AB");
+		members_with_id_prefix(&members, &query)
+			.map(|member| &*member.id)
+			.collect()
+	};
+
+	let by_name: Vec<&str> = {
+		let query = String::from("ASTR");
+		members_with_name_prefix(&members, &query)
+			.map(|member| &*member.id)
+			.collect()
+	};
+
+	assert_eq!(by_id, ["abcde", "abxyz"]);
+	assert_eq!(by_name, ["abxyz", "qwert"]);
+}
+
+#[test]
+fn members_are_equal_by_uuid() {
+	let astra = common::member(json!({ "name": "Astra" }));
+	let renamed = common::member(json!({ "name": "Astrid" }));
+
+	let members: HashSet<Member> = [astra.clone(), renamed.clone()].into_iter().collect();
+
+	assert_eq!(astra, renamed);
+	assert_eq!(members.len(), 1);
+	assert!(!astra.content_eq(&renamed));
+	assert!(astra.content_eq(&astra.clone()));
+}
+
+#[test]
+fn member_response_single() {
+	let response: MemberResponse = serde_json::from_value(common::member_json()).unwrap();
+
+	assert!(matches!(response, MemberResponse::Single(_)));
+	assert_eq!(response.into_vec().len(), 1);
+}
+
+#[test]
+fn member_response_list() {
+	let response: MemberResponse =
+		serde_json::from_value(json!([common::member_json(), common::member_json()])).unwrap();
+
+	assert!(matches!(response, MemberResponse::List(_)));
+	assert_eq!(response.into_vec().len(), 2);
+}
+
+#[test]
+fn message_count_number() {
+	let member = common::member(json!({ "message_count": 123 }));
+
+	assert_eq!(member.message_count, Some(123));
+}
+
+#[test]
+fn message_count_string() {
+	let member = common::member(json!({ "message_count": "123" }));
+
+	assert_eq!(member.message_count, Some(123));
+}
+
+#[test]
+fn message_count_absent() {
+	let mut json = common::member_json();
+	json.as_object_mut().unwrap().remove("message_count");
+
+	let member: Member = serde_json::from_value(json).unwrap();
+
+	assert_eq!(member.message_count, None);
+}
+
+#[test]
+fn member_without_restricted_fields() {
+	let json = common::member_json();
+	assert!(json.get("keep_proxy").is_none());
+	assert!(json.get("text_to_speech").is_none());
+
+	let member: Member = serde_json::from_value(json).unwrap();
+
+	assert!(!member.keep_proxy_tags);
+	assert!(!member.text_to_speech);
+}
+
+#[test]
+fn member_with_restricted_fields() {
+	let member = common::member(json!({ "keep_proxy": true, "text_to_speech": true }));
+
+	assert!(member.keep_proxy_tags);
+	assert!(member.text_to_speech);
+}
+
+#[test]
+fn is_own() {
+	let own = common::member(json!({
+		"privacy": {
+			"visibility": "public",
+			"name": "public",
+			"description": "private",
+			"birthday": "public",
+			"pronouns": "public",
+			"avatar": "public",
+			"metadata": "private",
+		},
+	}));
+	let other = common::member(json!({}));
+
+	assert!(own.is_own());
+	assert!(!other.is_own());
+}
+
+#[test]
+fn autoproxy_enabled() {
+	let enabled = common::member(json!({ "autoproxy_enabled": true }));
+	let disabled = common::member(json!({ "autoproxy_enabled": false }));
+	let null = common::member(json!({ "autoproxy_enabled": null }));
+
+	let mut json = common::member_json();
+	json.as_object_mut().unwrap().remove("autoproxy_enabled");
+	let absent: Member = serde_json::from_value(json).unwrap();
+
+	assert_eq!(enabled.autoproxy_enabled, Some(true));
+	assert_eq!(disabled.autoproxy_enabled, Some(false));
+	assert_eq!(null.autoproxy_enabled, None);
+	assert_eq!(absent.autoproxy_enabled, None);
+	assert!(enabled.is_autoproxy_enabled());
+	assert!(!null.is_autoproxy_enabled());
+}
+
+#[test]
+fn debug_redacted() {
+	let member = common::member(json!({ "description": "A secret description" }));
+
+	let debug = format!("{:?}", member.debug_redacted());
+
+	assert!(!debug.contains("A secret description"));
+	assert!(debug.contains("<redacted>"));
+	assert!(debug.contains("Astra"));
+}
+
+#[test]
+fn sort_by_uuid() {
+	let mut members = [
+		common::member(json!({ "id": "ccccc", "uuid": "c0000000-0000-4000-8000-000000000000" })),
+		common::member(json!({ "id": "aaaaa", "uuid": "a0000000-0000-4000-8000-000000000000" })),
+		common::member(json!({ "id": "bbbbb", "uuid": "b0000000-0000-4000-8000-000000000000" })),
+	];
+
+	sort_members_by_uuid(&mut members);
+
+	let ids: Vec<&str> = members.iter().map(|member| &*member.id).collect();
+	assert_eq!(ids, ["aaaaa", "bbbbb", "ccccc"]);
+}
+
+#[test]
+fn members_in_group_order() {
+	let members = [
+		common::member(json!({ "id": "aaaaa" })),
+		common::member(json!({ "id": "bbbbb" })),
+		common::member(json!({ "id": "ccccc" })),
+	];
+	let group = ["ccccc", "zzzzz", "aaaaa"].map(|id| ShortId::try_from(id).unwrap());
+
+	let ids: Vec<&str> = members_in_group(&group, &members)
+		.into_iter()
+		.map(|member| &*member.id)
+		.collect();
+
+	assert_eq!(ids, ["aaaaa", "ccccc"]);
+}
+
+#[test]
+fn created_unix_millis() {
+	let created = common::member(json!({ "created": "2023-01-01T00:00:00.123456Z" }));
+	let before_epoch = common::member(json!({ "created": "1969-12-31T23:59:59.9995Z" }));
+
+	assert_eq!(created.created_unix_millis(), Some(1672531200123));
+	assert_eq!(before_epoch.created_unix_millis(), Some(-1));
+	assert_eq!(common::member(json!({})).created_unix_millis(), None);
+}
+
+#[test]
+fn to_new_member() {
+	let member = common::member(json!({
+		"display_name": "Astra 🌟",
+		"proxy_tags": [{ "prefix": "a:", "suffix": null }],
+	}));
+
+	let new_member = member.to_new_member();
+
+	assert_eq!(new_member.name, member.name);
+	assert_eq!(new_member.display_name, member.display_name);
+	assert_eq!(new_member.proxy_tags, member.proxy_tags);
+	assert_eq!(new_member.privacy, None);
+}
@@ -0,0 +1,201 @@
+use plurallib::prelude::*;
+use serde_json::json;
+use time::OffsetDateTime;
+
+mod common;
+
+fn birthday_patch(birthday: Patchable<Option<OffsetDateTime>>) -> serde_json::Value {
+	serde_json::to_value(MemberPatch {
+		birthday,
+		..MemberPatch::default()
+	})
+	.unwrap()
+}
+
+#[test]
+fn unmodified_birthday_is_omitted() {
+	assert_eq!(birthday_patch(Patchable::Unmodified), json!({}));
+}
+
+#[test]
+fn cleared_birthday_is_null() {
+	assert_eq!(
+		birthday_patch(Patchable::Patched(None)),
+		json!({ "birthday": null })
+	);
+}
+
+#[test]
+fn patched_birthday_is_date() {
+	let birthday = OffsetDateTime::from_unix_timestamp(946684800).unwrap();
+
+	assert_eq!(
+		birthday_patch(Patchable::Patched(Some(birthday))),
+		json!({ "birthday": "2000-01-01" })
+	);
+}
+
+#[test]
+fn privacy_only_patch() {
+	let patch = MemberPatch::privacy_only(MemberPrivacyPatch {
+		visibility: Patchable::Patched(Privacy::Private),
+		..MemberPrivacyPatch::default()
+	});
+
+	assert_eq!(
+		serde_json::to_value(patch).unwrap(),
+		json!({ "privacy": { "visibility": "private" } })
+	);
+}
+
+#[test]
+fn empty_proxy_tag_is_omitted() {
+	let patch = MemberPatch {
+		proxy_tags: Patchable::Patched(vec![
+			ProxyTag::new(Some("a:"), None).unwrap(),
+			ProxyTag::new(None, Some("")).unwrap(),
+		]),
+		..MemberPatch::default()
+	};
+
+	assert_eq!(
+		serde_json::to_value(patch).unwrap(),
+		json!({ "proxy_tags": [{ "prefix": "a:", "suffix": null }] })
+	);
+}
+
+#[test]
+fn patchable_equality() {
+	assert_eq!(Patchable::Patched(1), Patchable::Patched(1));
+	assert_ne!(Patchable::Patched(1), Patchable::Patched(2));
+	assert_ne!(Patchable::Patched(1), Patchable::Unmodified);
+	assert_eq!(Patchable::<i32>::Unmodified, Patchable::Unmodified);
+	assert_ne!(
+		Patchable::Patched(None),
+		Patchable::<Option<i32>>::Unmodified
+	);
+}
+
+#[test]
+fn clear_is_null() {
+	let patch = MemberPatch {
+		display_name: Patchable::clear(),
+		..MemberPatch::default()
+	};
+
+	assert_eq!(
+		serde_json::to_value(patch).unwrap(),
+		json!({ "display_name": null })
+	);
+}
+
+#[test]
+fn patchable_option_interop() {
+	let double = |value: i32| Patchable::Patched(value * 2);
+
+	assert_eq!(
+		Patchable::Patched(2).and_then(double),
+		Patchable::Patched(4)
+	);
+	assert_eq!(
+		Patchable::Unmodified.and_then(double),
+		Patchable::Unmodified
+	);
+	assert_eq!(
+		Patchable::Unmodified.or(Patchable::Patched(1)),
+		Patchable::Patched(1)
+	);
+	assert_eq!(
+		Patchable::Patched(2).or(Patchable::Patched(1)),
+		Patchable::Patched(2)
+	);
+	assert_eq!(Patchable::Patched(2).into_option(), Some(2));
+	assert_eq!(Patchable::<i32>::Unmodified.into_option(), None);
+	assert_eq!(Patchable::from_option(Some(2)), Patchable::Patched(2));
+	assert_eq!(Patchable::<i32>::from_option(None), Patchable::Unmodified);
+}
+
+#[test]
+fn patchable_take_and_replace() {
+	let mut patchable = Patchable::Patched(1);
+
+	assert_eq!(patchable.take(), Patchable::Patched(1));
+	assert_eq!(patchable, Patchable::Unmodified);
+	assert_eq!(patchable.replace(2), Patchable::Unmodified);
+	assert_eq!(patchable, Patchable::Patched(2));
+}
+
+fn name(name: &str) -> Patchable<LimitedStr<100>> {
+	Patchable::Patched(LimitedStr::try_from(name).unwrap())
+}
+
+#[test]
+fn merge_overlapping_patches() {
+	let first = MemberPatch {
+		name: name("Astra"),
+		privacy: Patchable::Patched(MemberPrivacyPatch {
+			name: Patchable::Patched(Privacy::Private),
+			..MemberPrivacyPatch::default()
+		}),
+		..MemberPatch::default()
+	};
+	let second = MemberPatch {
+		name: name("Astrid"),
+		privacy: Patchable::Patched(MemberPrivacyPatch {
+			avatar: Patchable::Patched(Privacy::Private),
+			..MemberPrivacyPatch::default()
+		}),
+		..MemberPatch::default()
+	};
+
+	let merged = first.merge(second);
+
+	assert_eq!(merged.name, name("Astrid"));
+	assert_eq!(
+		merged.privacy,
+		Patchable::Patched(MemberPrivacyPatch {
+			name: Patchable::Patched(Privacy::Private),
+			avatar: Patchable::Patched(Privacy::Private),
+			..MemberPrivacyPatch::default()
+		})
+	);
+}
+
+#[test]
+fn merge_disjoint_patches() {
+	let first = MemberPatch {
+		name: name("Astra"),
+		..MemberPatch::default()
+	};
+	let second = MemberPatch {
+		text_to_speech: Patchable::Patched(true),
+		..MemberPatch::default()
+	};
+
+	assert_eq!(
+		first.merge(second),
+		MemberPatch {
+			name: name("Astra"),
+			text_to_speech: Patchable::Patched(true),
+			..MemberPatch::default()
+		}
+	);
+}
+
+#[test]
+fn is_noop_against() {
+	let member = common::member(json!({ "name": "Astra" }));
+
+	let same = MemberPatch {
+		name: name("Astra"),
+		..MemberPatch::default()
+	};
+	let renamed = MemberPatch {
+		name: name("Astrid"),
+		..MemberPatch::default()
+	};
+
+	assert!(MemberPatch::default().is_noop_against(&member));
+	assert!(same.is_noop_against(&member));
+	assert!(!renamed.is_noop_against(&member));
+}
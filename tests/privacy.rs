@@ -0,0 +1,76 @@
+use plurallib::prelude::*;
+use serde_json::json;
+use std::collections::HashSet;
+
+#[test]
+fn unrecognized_privacy_is_unknown() {
+	let privacy: Privacy = serde_json::from_value(json!("trusted")).unwrap();
+
+	assert_eq!(privacy, Privacy::Unknown);
+}
+
+#[test]
+fn unknown_privacy_is_not_serialized() {
+	assert!(serde_json::to_value(Privacy::Unknown).is_err());
+	assert_eq!(serde_json::to_value(Privacy::Private).unwrap(), "private");
+}
+
+#[test]
+fn privacy_from_str() {
+	assert_eq!("public".parse(), Ok(Privacy::Public));
+	assert_eq!(Privacy::try_from("private"), Ok(Privacy::Private));
+	assert!("trusted".parse::<Privacy>().is_err());
+}
+
+#[test]
+fn privacy_display() {
+	assert_eq!(Privacy::Public.to_string(), "public");
+	assert_eq!(Privacy::Private.to_string(), "private");
+}
+
+#[test]
+fn member_privacy_default_is_public() {
+	let privacy = MemberPrivacy::default();
+
+	assert_eq!(privacy, MemberPrivacy::all(Privacy::Public));
+	assert_eq!(privacy.description, Privacy::Public);
+	assert_eq!(privacy.metadata, Privacy::Public);
+}
+
+#[test]
+fn member_privacy_equality() {
+	let private = MemberPrivacy {
+		name: Privacy::Private,
+		..MemberPrivacy::default()
+	};
+
+	assert_ne!(private, MemberPrivacy::default());
+	assert_eq!(private, private.clone());
+
+	let patches: HashSet<MemberPrivacyPatch> = [
+		MemberPrivacyPatch::PUBLIC,
+		MemberPrivacyPatch::PRIVATE,
+		MemberPrivacyPatch::PUBLIC,
+	]
+	.into_iter()
+	.collect();
+
+	assert_eq!(patches.len(), 2);
+}
+
+#[test]
+fn member_visibility_with_mixed_privacy() {
+	let privacy = MemberPrivacy {
+		name: Privacy::Private,
+		birthday: Privacy::Unknown,
+		..MemberPrivacy::default()
+	};
+
+	let visibility = MemberVisibility::from(&privacy);
+
+	assert_eq!(
+		visibility,
+		MemberVisibility::all() - MemberVisibility::NAME - MemberVisibility::BIRTHDAY
+	);
+	assert!(visibility.contains(MemberVisibility::DESCRIPTION));
+}
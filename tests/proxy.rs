@@ -0,0 +1,64 @@
+use plurallib::proxy::ProxyMatcher;
+use serde_json::json;
+
+mod common;
+
+#[test]
+fn match_message() {
+	let members = [common::member(json!({
+		"proxy_tags": [{ "prefix": "[", "suffix": "]" }],
+	}))];
+	let matcher = ProxyMatcher::new(&members);
+
+	let (_, content) = matcher.match_message("[hello]").unwrap();
+
+	assert_eq!(content, "hello");
+}
+
+#[test]
+fn match_message_without_content() {
+	let members = [common::member(json!({
+		"proxy_tags": [{ "prefix": "[", "suffix": "]" }],
+	}))];
+	let matcher = ProxyMatcher::new(&members);
+
+	assert!(matcher.match_message("[]").is_none());
+	assert!(matcher.match_message("[  ]").is_none());
+}
+
+#[test]
+fn match_message_prefers_longer_tag() {
+	let members = [
+		common::member(json!({
+			"id": "short",
+			"proxy_tags": [{ "prefix": "a:", "suffix": null }],
+		})),
+		common::member(json!({
+			"id": "longr",
+			"proxy_tags": [{ "prefix": "aé:", "suffix": null }],
+		})),
+	];
+	let matcher = ProxyMatcher::new(&members);
+
+	let (member, content) = matcher.match_message("aé:hello").unwrap();
+
+	assert_eq!(&*member.id, "longr");
+	assert_eq!(content, "hello");
+}
+
+#[test]
+fn proxy_tags_display() {
+	let member = common::member(json!({
+		"proxy_tags": [
+			{ "prefix": "[", "suffix": "]" },
+			{ "prefix": null, "suffix": "-" },
+		],
+	}));
+
+	assert_eq!(member.proxy_tags_display(), "[text], text-");
+}
+
+#[test]
+fn proxy_tags_display_without_tags() {
+	assert_eq!(common::member(json!({})).proxy_tags_display(), "");
+}
@@ -0,0 +1,81 @@
+use plurallib::prelude::*;
+
+#[test]
+fn multibyte_tag_at_limit() {
+	let prefix = "é".repeat(50);
+	let suffix = "🌟".repeat(50);
+
+	assert!(ProxyTag::new(Some(prefix), Some(suffix)).is_ok());
+}
+
+#[test]
+fn multibyte_tag_over_limit() {
+	let prefix = "é".repeat(50);
+	let suffix = "🌟".repeat(51);
+
+	assert_eq!(
+		ProxyTag::new(Some(prefix), Some(suffix)),
+		Err(ProxyTagExceededLimitError { length: 101 })
+	);
+}
+
+#[test]
+fn builder_blames_suffix() {
+	let tag = ProxyTag::builder()
+		.prefix("a".repeat(60))
+		.suffix("b".repeat(41))
+		.build();
+
+	assert_eq!(
+		tag,
+		Err(ProxyTagComponentError {
+			component: ProxyTagComponent::Suffix,
+			length: 101,
+		})
+	);
+}
+
+#[test]
+fn builder_blames_prefix() {
+	let tag = ProxyTag::builder()
+		.prefix("a".repeat(101))
+		.suffix("b")
+		.build();
+
+	assert_eq!(
+		tag.map_err(|error| error.component),
+		Err(ProxyTagComponent::Prefix)
+	);
+}
+
+#[test]
+fn from_boxed() {
+	let tag = ProxyTag::from_boxed(Some("a:".into()), None).unwrap();
+
+	assert_eq!(tag.prefix.as_deref(), Some("a:"));
+	assert_eq!(tag.suffix, None);
+	assert_eq!(ProxyTag::from_boxed(None, None), Err(ProxyTagError::Empty));
+}
+
+#[test]
+fn exceeded_limit_reports_length() {
+	let error = ProxyTag::new(Some("a".repeat(120)), Some("b".repeat(3))).unwrap_err();
+
+	assert_eq!(error.length, 123);
+	assert_eq!(
+		error.to_string(),
+		"proxy tags were 123 characters but must not exceed 100"
+	);
+	assert_eq!(
+		ProxyTagError::from(error).to_string(),
+		"proxy tags were 123 characters but must not exceed 100"
+	);
+}
+
+#[test]
+fn display_escaped() {
+	let tag = ProxyTag::new(Some("**"), Some("_|")).unwrap();
+
+	assert_eq!(tag.to_string(), "**text_|");
+	assert_eq!(tag.display_escaped(), r"\*\*text\_\|");
+}
@@ -0,0 +1,142 @@
+use plurallib::prelude::*;
+
+mod common;
+
+#[test]
+fn member_ref_as_reference() {
+	let member = MemberRef::try_from("ptckn").unwrap();
+
+	assert!(matches!(
+		member.as_reference(),
+		Ok(GenericRef::ShortId(short)) if &*short == "ptckn"
+	));
+}
+
+#[test]
+fn group_ref_as_reference() {
+	let group = GroupRef::try_from("30523e4f-dd68-4b91-8ee0-59c7598db16c").unwrap();
+
+	assert!(matches!(group.as_reference(), Ok(GenericRef::Uuid(_))));
+}
+
+#[test]
+fn short_id_with_surrounding_whitespace() {
+	assert_eq!(
+		ShortId::try_from(" ptckn"),
+		Err(ShortError::SurroundingWhitespace)
+	);
+	assert_eq!(
+		ShortId::try_from("ptckn\n"),
+		Err(ShortError::SurroundingWhitespace)
+	);
+}
+
+#[test]
+fn short_id_parse_trimmed() {
+	assert_eq!(&*ShortId::parse_trimmed(" ptckn\n").unwrap(), "ptckn");
+	assert_eq!(&*ShortId::parse_trimmed("\u{200B}ptckn").unwrap(), "ptckn");
+	assert_eq!(
+		ShortId::parse_trimmed(" ptck "),
+		Err(ShortError::IncorrectLength)
+	);
+}
+
+#[test]
+fn generic_ref_from_uuid() {
+	let uuid = "30523e4f-dd68-4b91-8ee0-59c7598db16c";
+
+	assert!(matches!(
+		GenericRef::try_from(uuid),
+		Ok(GenericRef::Uuid(parsed)) if parsed.to_string() == uuid
+	));
+}
+
+#[test]
+fn generic_ref_from_invalid() {
+	assert!(matches!(
+		GenericRef::try_from("pt-ckn"),
+		Err(RefParseError::Invalid {
+			short: ShortError::IncorrectLength,
+			..
+		})
+	));
+}
+
+#[test]
+fn short_id_from_bytes() {
+	assert_eq!(&*ShortId::from_bytes(b"ptckn").unwrap(), "ptckn");
+	assert_eq!(
+		ShortId::from_bytes(b"ptck"),
+		Err(ShortError::IncorrectLength)
+	);
+	assert_eq!(
+		ShortId::from_bytes(b"ptck\xff"),
+		Err(ShortError::InvalidCharacters)
+	);
+}
+
+#[test]
+fn id_serde_round_trip() {
+	for id in ["ptckn", "30523e4f-dd68-4b91-8ee0-59c7598db16c"] {
+		let parsed: Id = serde_json::from_value(serde_json::json!(id)).unwrap();
+
+		assert_eq!(serde_json::to_value(&parsed).unwrap(), id);
+	}
+
+	let uuid: Id = serde_json::from_str(r#""30523e4f-dd68-4b91-8ee0-59c7598db16c""#).unwrap();
+	let short: Id = serde_json::from_str(r#""ptckn""#).unwrap();
+
+	assert!(matches!(uuid, Id::Uuid(_)));
+	assert!(matches!(short, Id::Short(_)));
+}
+
+#[test]
+fn short_id_equals_reference() {
+	let short = ShortId::try_from("ptckn").unwrap();
+
+	assert!(short == GenericRef::try_from("ptckn").unwrap());
+	assert!(short != GenericRef::try_from("abcde").unwrap());
+	assert!(GenericRef::try_from("ptckn").unwrap() == short);
+	assert!(short == SystemRef::try_from("ptckn").unwrap());
+	assert!(short != SystemRef::Current);
+}
+
+#[test]
+fn system_ref_from_mention() {
+	for mention in [
+		"<@521031433972744193>",
+		"<@!521031433972744193>",
+		"521031433972744193",
+	] {
+		assert!(matches!(
+			SystemRef::from_mention(mention),
+			Ok(SystemRef::Snowflake(521031433972744193))
+		));
+	}
+
+	assert!(matches!(
+		SystemRef::from_mention("<@ptckn>"),
+		Err(RefParseError::InvalidMention)
+	));
+}
+
+fn reference<R: AsReference>(value: R) -> String {
+	value.as_reference().unwrap().to_string()
+}
+
+#[test]
+fn as_reference_for_each_type() {
+	let uuid = uuid::Uuid::parse_str("30523e4f-dd68-4b91-8ee0-59c7598db16c").unwrap();
+	let short = ShortId::try_from("ptckn").unwrap();
+
+	assert_eq!(reference("ptckn"), "ptckn");
+	assert_eq!(reference(&short), "ptckn");
+	assert_eq!(reference(uuid), uuid.to_string());
+	assert_eq!(reference(Id::from(short.clone())), "ptckn");
+	assert_eq!(reference(GenericRef::from(uuid)), uuid.to_string());
+	assert_eq!(
+		reference(common::member(serde_json::json!({}))),
+		uuid.to_string()
+	);
+	assert!("pt ckn".as_reference().is_err());
+}
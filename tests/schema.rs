@@ -0,0 +1,12 @@
+#![cfg(feature = "schemars")]
+
+use plurallib::prelude::*;
+
+#[test]
+fn member_patch_schema_limits() {
+	let schema = serde_json::to_value(schemars::schema_for!(MemberPatch)).unwrap();
+
+	assert_eq!(schema["properties"]["name"]["maxLength"], 100);
+	assert_eq!(schema["properties"]["display_name"]["maxLength"], 100);
+	assert_eq!(schema["properties"]["description"]["maxLength"], 1000);
+}
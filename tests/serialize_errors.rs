@@ -0,0 +1,47 @@
+#![cfg(feature = "serialize_errors")]
+
+use plurallib::prelude::*;
+use serde_json::json;
+
+#[test]
+fn serialize_short_error() {
+	let error = ShortId::try_from("ptck").unwrap_err();
+
+	assert_eq!(
+		serde_json::to_value(error).unwrap(),
+		json!({
+			"error": "incorrect_length",
+			"message": "A ShortId should only be 5 characters in length",
+		})
+	);
+}
+
+#[test]
+fn serialize_exceeded_limit_error() {
+	let error = LimitedStr::<4>::try_from("Astra").unwrap_err();
+
+	assert_eq!(
+		serde_json::to_value(&error).unwrap()["error"],
+		"exceeded_limit"
+	);
+	assert_eq!(
+		serde_json::to_value(error.into_owned()).unwrap(),
+		json!({
+			"error": "exceeded_limit",
+			"message": "string of length 5 should not exceed length 4",
+		})
+	);
+}
+
+#[test]
+fn serialize_proxy_tag_exceeded_limit_error() {
+	let error = ProxyTag::new(Some("a".repeat(101)), None).unwrap_err();
+
+	assert_eq!(
+		serde_json::to_value(error).unwrap(),
+		json!({
+			"error": "proxy_tag_exceeded_limit",
+			"message": "proxy tags were 101 characters but must not exceed 100",
+		})
+	);
+}
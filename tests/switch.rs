@@ -0,0 +1,98 @@
+use plurallib::models::switch::{dedup_switches, front_durations, Switch};
+use plurallib::prelude::*;
+use serde_json::json;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+mod common;
+
+fn switch(id: u128, timestamp: i64, members: &[&str]) -> Switch {
+	Switch {
+		id: Uuid::from_u128(id),
+		timestamp: OffsetDateTime::from_unix_timestamp(timestamp).unwrap(),
+		members: members
+			.iter()
+			.map(|member| ShortId::try_from(*member).unwrap())
+			.collect(),
+	}
+}
+
+fn at(timestamp: i64) -> OffsetDateTime {
+	OffsetDateTime::from_unix_timestamp(timestamp).unwrap()
+}
+
+fn short(id: &str) -> ShortId {
+	ShortId::try_from(id).unwrap()
+}
+
+#[test]
+fn front_durations_of_two_switches() {
+	let switches = [
+		switch(2, 100, &["bbbbb"]),
+		switch(1, 0, &["aaaaa", "bbbbb"]),
+	];
+
+	assert_eq!(
+		front_durations(&switches, at(150)),
+		[
+			(short("aaaaa"), Duration::seconds(100)),
+			(short("bbbbb"), Duration::seconds(150)),
+		]
+	);
+}
+
+#[test]
+fn front_durations_without_switches() {
+	assert_eq!(front_durations(&[], at(150)), []);
+}
+
+#[test]
+fn front_durations_of_single_switch() {
+	let switches = [switch(1, 100, &["aaaaa"])];
+
+	assert_eq!(
+		front_durations(&switches, at(150)),
+		[(short("aaaaa"), Duration::seconds(50))]
+	);
+}
+
+#[test]
+fn front_durations_with_now_before_last_switch() {
+	let switches = [switch(1, 0, &["aaaaa"]), switch(2, 100, &["bbbbb"])];
+
+	assert_eq!(
+		front_durations(&switches, at(50)),
+		[
+			(short("aaaaa"), Duration::seconds(100)),
+			(short("bbbbb"), Duration::ZERO),
+		]
+	);
+}
+
+#[test]
+fn resolve_members() {
+	let members = [
+		common::member(json!({ "id": "aaaaa" })),
+		common::member(json!({ "id": "bbbbb" })),
+	];
+	let switch = switch(1, 0, &["bbbbb", "zzzzz", "aaaaa"]);
+
+	let resolved: Vec<Option<&str>> = switch
+		.resolve_members(&members)
+		.into_iter()
+		.map(|member| member.map(|member| &*member.id))
+		.collect();
+
+	assert_eq!(resolved, [Some("bbbbb"), None, Some("aaaaa")]);
+}
+
+#[test]
+fn dedup_overlapping_pages() {
+	let first_page = [switch(3, 300, &["ccccc"]), switch(2, 200, &["bbbbb"])];
+	let second_page = [switch(2, 200, &["bbbbb"]), switch(1, 100, &["aaaaa"])];
+
+	let switches = dedup_switches(first_page.into_iter().chain(second_page).collect());
+
+	let ids: Vec<Uuid> = switches.iter().map(|switch| switch.id).collect();
+	assert_eq!(ids, [1, 2, 3].map(Uuid::from_u128));
+}
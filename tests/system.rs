@@ -0,0 +1,85 @@
+use plurallib::models::system::SystemSettings;
+use plurallib::prelude::*;
+use serde_json::json;
+
+mod common;
+
+#[test]
+fn tag_at_limit() {
+	let system = common::system(json!({ "tag": "a".repeat(79) }));
+
+	assert_eq!(system.tag.map(|tag| tag.len()), Some(79));
+}
+
+#[test]
+fn tag_over_limit() {
+	let json = common::with(common::system_json(), json!({ "tag": "a".repeat(80) }));
+
+	assert!(serde_json::from_value::<System>(json).is_err());
+}
+
+#[test]
+fn counts_present() {
+	let system = common::system(json!({ "member_count": 12, "group_count": "3" }));
+
+	assert_eq!(system.counts(), (12, 3));
+}
+
+#[test]
+fn counts_absent() {
+	let system = common::system(json!({}));
+
+	assert_eq!((system.member_count, system.group_count), (None, None));
+	assert_eq!(system.counts(), (0, 0));
+}
+
+#[test]
+fn redacted_private_description() {
+	let system = common::system(json!({
+		"description": "A description",
+		"pronouns": "they/them",
+		"privacy": {
+			"description_privacy": "private",
+			"pronoun_privacy": "public",
+			"member_list_privacy": "public",
+			"group_list_privacy": "public",
+			"front_privacy": "public",
+			"front_history_privacy": "public",
+		},
+	}));
+
+	let redacted = system.redacted();
+
+	assert_eq!(redacted.description, None);
+	assert_eq!(redacted.pronouns.as_deref(), Some("they/them"));
+	assert!(redacted.privacy.is_none());
+}
+
+#[test]
+fn redacted_without_privacy() {
+	let system = common::system(json!({ "description": "A description" }));
+
+	assert_eq!(
+		system.redacted().description.as_deref(),
+		Some("A description")
+	);
+}
+
+#[test]
+fn system_settings() {
+	let settings: SystemSettings = serde_json::from_value(json!({
+		"timezone": "Europe/London",
+		"pings_enabled": true,
+		"member_default_private": false,
+		"group_default_private": true,
+		"show_private_info": true,
+		"member_limit": 1000,
+		"group_limit": 250,
+	}))
+	.unwrap();
+
+	assert_eq!(&*settings.timezone, "Europe/London");
+	assert!(settings.pings_enabled);
+	assert!(settings.group_default_private);
+	assert_eq!((settings.member_limit, settings.group_limit), (1000, 250));
+}
@@ -0,0 +1,140 @@
+use plurallib::prelude::*;
+use time::{Date, Month};
+
+fn name(name: &str) -> LimitedStr<100> {
+	LimitedStr::try_from(name).unwrap()
+}
+
+#[test]
+fn new_member_with_name() {
+	assert_eq!(NewMember::new(name("Astra")).validate(), Ok(()));
+}
+
+#[test]
+fn new_member_with_empty_name() {
+	assert_eq!(
+		NewMember::new(name(" ")).validate(),
+		Err(MemberValidationError::EmptyName)
+	);
+}
+
+#[test]
+fn member_patch_with_name() {
+	let patch = MemberPatch {
+		name: Patchable::Patched(name("Astra")),
+		..MemberPatch::default()
+	};
+
+	assert_eq!(patch.validate(), Ok(()));
+}
+
+#[test]
+fn member_patch_with_empty_name() {
+	let patch = MemberPatch {
+		name: Patchable::Patched(name("")),
+		..MemberPatch::default()
+	};
+
+	assert_eq!(patch.validate(), Err(MemberValidationError::EmptyName));
+}
+
+#[test]
+fn member_patch_clearing_display_name() {
+	let patch = MemberPatch {
+		display_name: Patchable::clear(),
+		..MemberPatch::default()
+	};
+
+	assert_eq!(patch.validate(), Ok(()));
+}
+
+#[test]
+fn member_patch_with_whitespace_name() {
+	let patch = MemberPatch {
+		name: Patchable::Patched(name("\t \n")),
+		display_name: Patchable::clear(),
+		..MemberPatch::default()
+	};
+
+	assert_eq!(patch.validate(), Err(MemberValidationError::EmptyName));
+}
+
+fn proxy_tags(count: usize) -> Vec<ProxyTag> {
+	(0..count)
+		.map(|index| ProxyTag::new(Some(format!("{index}:")), None).unwrap())
+		.collect()
+}
+
+#[test]
+fn proxy_tags_at_limit() {
+	let mut member = NewMember::new(name("Astra"));
+	member.proxy_tags = proxy_tags(100);
+
+	assert_eq!(member.validate(), Ok(()));
+}
+
+#[test]
+fn proxy_tags_over_limit() {
+	let mut member = NewMember::new(name("Astra"));
+	member.proxy_tags = proxy_tags(101);
+
+	let patch = MemberPatch {
+		proxy_tags: Patchable::Patched(proxy_tags(101)),
+		..MemberPatch::default()
+	};
+
+	assert_eq!(
+		member.validate(),
+		Err(MemberValidationError::ProxyTagCountExceeded(101))
+	);
+	assert_eq!(
+		patch.validate(),
+		Err(MemberValidationError::ProxyTagCountExceeded(101))
+	);
+}
+
+#[test]
+fn empty_proxy_tags_are_not_counted() {
+	let mut member = NewMember::new(name("Astra"));
+	member.proxy_tags = proxy_tags(100);
+	member
+		.proxy_tags
+		.push(ProxyTag::new(Some(""), None).unwrap());
+
+	assert_eq!(member.validate(), Ok(()));
+}
+
+fn birthday(year: i32) -> MemberPatch {
+	let date = Date::from_calendar_date(year, Month::January, 2).unwrap();
+
+	MemberPatch {
+		birthday: Patchable::Patched(Some(date.midnight().assume_utc())),
+		..MemberPatch::default()
+	}
+}
+
+#[test]
+fn plausible_birthday() {
+	assert_eq!(birthday(2000).validate(), Ok(()));
+}
+
+#[test]
+fn birthday_with_hidden_year() {
+	assert_eq!(birthday(4).validate(), Ok(()));
+}
+
+#[test]
+fn birthday_in_far_future() {
+	assert_eq!(
+		birthday(3000).validate(),
+		Err(MemberValidationError::ImplausibleBirthdayYear(3000))
+	);
+
+	let mut member = NewMember::new(name("Astra"));
+	member.birthday = birthday(3000).birthday.into_option().flatten();
+
+	assert_eq!(
+		member.validate(),
+		Err(MemberValidationError::ImplausibleBirthdayYear(3000))
+	);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "serde_json")]
+
+use plurallib::prelude::*;
+use serde_json::json;
+
+mod common;
+
+#[test]
+fn member_from_value() {
+	let member = Member::try_from(common::member_json()).unwrap();
+
+	assert_eq!(&*member.name, "Astra");
+}
+
+#[test]
+fn member_from_malformed_value() {
+	assert!(Member::try_from(json!({ "id": "ptckn" })).is_err());
+}
+
+#[test]
+fn system_from_value() {
+	let system = System::try_from(common::system_json()).unwrap();
+
+	assert_eq!(system.name.as_deref(), Some("Astralchroma"));
+}
+
+#[test]
+fn system_from_malformed_value() {
+	assert!(System::try_from(json!({ "id": 5 })).is_err());
+}
+
+#[test]
+fn member_from_json() {
+	let member = Member::from_json(&common::member_json().to_string()).unwrap();
+
+	assert_eq!(&*member.name, "Astra");
+}
+
+#[test]
+fn member_from_json_with_invalid_system() {
+	let json = common::with(common::member_json(), json!({ "system": "ABCDE" }));
+
+	let error = Member::from_json(&json.to_string()).unwrap_err();
+
+	assert_eq!(error.path, "system");
+}
@@ -0,0 +1,72 @@
+use plurallib::prelude::*;
+use rgb::RGB8;
+use time::OffsetDateTime;
+
+/// Every field of the patch is modified, so that each of them appears in the serialized body.
+fn full_member_patch() -> MemberPatch {
+	MemberPatch {
+		name: Patchable::Patched(LimitedStr::try_from("Astra").unwrap()),
+		display_name: Patchable::Patched(Some(LimitedStr::try_from("Astra 🌟").unwrap())),
+		color: Patchable::Patched(Some(RGB8::new(0xff, 0x80, 0x00))),
+		birthday: Patchable::Patched(Some(
+			OffsetDateTime::from_unix_timestamp(946684800).unwrap(),
+		)),
+		pronouns: Patchable::Patched(Some(LimitedStr::try_from("she/her").unwrap())),
+		avatar: Patchable::Patched(Some(
+			LimitedUrl::try_from("https://example.com/avatar.png").unwrap(),
+		)),
+		webhook_avatar: Patchable::Patched(Some(
+			LimitedUrl::try_from("https://example.com/webhook.png").unwrap(),
+		)),
+		banner: Patchable::Patched(None),
+		description: Patchable::Patched(Some(LimitedStr::try_from("A description").unwrap())),
+		proxy_tags: Patchable::Patched(vec![ProxyTag::new(Some("a:"), None).unwrap()]),
+		keep_proxy_tags: Patchable::Patched(true),
+		text_to_speech: Patchable::Patched(false),
+		autoproxy_enabled: Patchable::Patched(Some(true)),
+		privacy: Patchable::Patched(MemberPrivacyPatch {
+			visibility: Patchable::Patched(Privacy::Private),
+			..MemberPrivacyPatch::PUBLIC
+		}),
+	}
+}
+
+/// Pins the wire format of `MemberPatch`, so renamed fields or changed value formats show up as a difference from the
+/// committed body. Keys are compared in sorted order, as the order of fields in the body doesn't matter.
+#[test]
+fn member_patch_wire_format() {
+	let actual = serde_json::to_value(full_member_patch()).unwrap();
+	let expected: serde_json::Value =
+		serde_json::from_str(include_str!("fixtures/member_patch.json")).unwrap();
+
+	assert_eq!(
+		serde_json::to_string_pretty(&actual).unwrap(),
+		serde_json::to_string_pretty(&expected).unwrap()
+	);
+}
+
+#[test]
+fn new_member_drops_empty_proxy_tags() {
+	let mut member = NewMember::new(LimitedStr::try_from("Astra").unwrap());
+	member.proxy_tags = vec![
+		ProxyTag::new(Some(""), None).unwrap(),
+		ProxyTag::new(Some("a:"), None).unwrap(),
+	];
+
+	let body = serde_json::to_value(&member).unwrap();
+
+	assert_eq!(
+		body["proxy_tags"],
+		serde_json::json!([{ "prefix": "a:", "suffix": null }])
+	);
+}
+
+#[test]
+fn new_member_omits_only_empty_proxy_tags() {
+	let mut member = NewMember::new(LimitedStr::try_from("Astra").unwrap());
+	member.proxy_tags = vec![ProxyTag::new(Some(""), Some("")).unwrap()];
+
+	let body = serde_json::to_value(&member).unwrap();
+
+	assert!(body.get("proxy_tags").is_none());
+}